@@ -0,0 +1,312 @@
+/// The fixed outer ring (stabilis) of Alberti's disk.
+pub const STABILIS: &str = "ABCDEFGILMNOPQRSTVXZ1234";
+/// The movable inner ring (mobilis) of Alberti's disk.
+pub const MOBILIS: &str = "gklnprtvz&xysomqihfdbace";
+
+/// How the inner ring of the disk is turned while a message is enciphered.
+///
+/// Positions count the characters enciphered so far, so a signal at position 2
+/// resets the disk before the third character.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rotation {
+    /// The disk keeps its starting setting.
+    Fixed,
+    /// The inner ring turns `step` places, moving the index letter along the
+    /// outer ring, after every `period` characters.
+    Periodic { period: usize, step: usize },
+    /// The index letter is moved under the given outer character at each
+    /// position, and the outer character is written into the ciphertext to signal
+    /// the new setting.
+    Signals(Vec<(usize, char)>),
+}
+
+/// Alberti Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Alberti {
+    outer: Vec<char>,
+    inner: Vec<char>,
+    index: char,
+    setting: char,
+    rotation: Rotation,
+}
+
+impl Alberti {
+    /// Initializes an Alberti cipher disk with its outer and inner rings, the
+    /// index letter on the inner ring, the outer character it starts under, and
+    /// how the disk is turned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::alberti::{Alberti, Rotation, MOBILIS, STABILIS};
+    ///
+    /// let a = Alberti::new(STABILIS, MOBILIS, 'k', 'B', Rotation::Fixed).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the rings are not the same length, either ring repeats
+    /// a character, the rings share a character, the index letter is not on the
+    /// inner ring, a setting is not on the outer ring or a period is zero.
+    ///
+    pub fn new(
+        outer: &str,
+        inner: &str,
+        index: char,
+        setting: char,
+        rotation: Rotation,
+    ) -> Result<Self, String> {
+        let outer = outer.chars().collect::<Vec<char>>();
+        let inner = inner.chars().collect::<Vec<char>>();
+        if outer.is_empty() || outer.len() != inner.len() {
+            return Err(String::from("Rings must be the same length"));
+        }
+        for ring in &[&outer, &inner] {
+            if ring.iter().enumerate().any(|(i, c)| ring[..i].contains(c)) {
+                return Err(String::from("Ring characters must be unique"));
+            }
+        }
+        if outer.iter().any(|c| inner.contains(c)) {
+            return Err(String::from("Rings must not share characters"));
+        }
+        if !inner.contains(&index) {
+            return Err(String::from("Index letter must be on the inner ring"));
+        }
+
+        let mut settings = vec![setting];
+        match rotation {
+            Rotation::Periodic { period: 0, .. } => {
+                return Err(String::from("Period must be greater than zero"));
+            }
+            Rotation::Signals(ref signals) => settings.extend(signals.iter().map(|s| s.1)),
+            _ => (),
+        }
+        if settings.iter().any(|s| !outer.contains(s)) {
+            return Err(String::from("Settings must be on the outer ring"));
+        }
+
+        Ok(Alberti {
+            outer,
+            inner,
+            index,
+            setting,
+            rotation,
+        })
+    }
+
+    /// Enciphers a message with an Alberti cipher.
+    ///
+    /// Letters are matched to the outer ring without regard to case. Characters
+    /// other than letters and digits are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::alberti::{Alberti, Rotation, MOBILIS, STABILIS};
+    ///
+    /// let signals = Rotation::Signals(vec![(2, 'D')]);
+    /// let a = Alberti::new(STABILIS, MOBILIS, 'k', 'B', signals).unwrap();
+    /// assert_eq!("lgDe", a.encipher("cab").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the message has a letter or digit that is not on the
+    /// outer ring.
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let mut offset = self.offset(self.setting);
+        let mut count = 0;
+        let mut ciphertext = String::new();
+        for c in plaintext.chars() {
+            let position = match Alberti::find(&self.outer, c) {
+                Some(position) => position,
+                None if c.is_alphanumeric() => {
+                    return Err(format!("'{}' is not on the outer ring", c));
+                }
+                None => {
+                    ciphertext.push(c);
+                    continue;
+                }
+            };
+
+            if let Rotation::Signals(ref signals) = self.rotation {
+                for &(_, setting) in signals.iter().filter(|s| s.0 == count) {
+                    offset = self.offset(setting);
+                    ciphertext.push(setting);
+                }
+            }
+            ciphertext.push(self.inner[self.shift(position, offset, false)]);
+
+            count += 1;
+            offset = self.turn(offset, count);
+        }
+        Ok(ciphertext)
+    }
+
+    /// Deciphers a message with an Alberti cipher.
+    ///
+    /// With signalled rotation, characters of the outer ring in the ciphertext
+    /// reset the disk. Characters other than letters and digits are left
+    /// unchanged, and the case of the inner ring must be matched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::alberti::{Alberti, Rotation, MOBILIS, STABILIS};
+    ///
+    /// let signals = Rotation::Signals(Vec::new());
+    /// let a = Alberti::new(STABILIS, MOBILIS, 'k', 'B', signals).unwrap();
+    /// assert_eq!("CAB", a.decipher("lgDe").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the message has a letter or digit that is not on the
+    /// inner ring, or on the outer ring as a signal.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let signalled = matches!(self.rotation, Rotation::Signals(_));
+
+        let mut offset = self.offset(self.setting);
+        let mut count = 0;
+        let mut plaintext = String::new();
+        for c in ciphertext.chars() {
+            if let Some(position) = self.inner.iter().position(|&i| i == c) {
+                plaintext.push(self.outer[self.shift(position, offset, true)]);
+                count += 1;
+                offset = self.turn(offset, count);
+            } else if signalled && self.outer.contains(&c) {
+                offset = self.offset(c);
+            } else if c.is_alphanumeric() {
+                return Err(format!("'{}' is not on the inner ring", c));
+            } else {
+                plaintext.push(c);
+            }
+        }
+        Ok(plaintext)
+    }
+
+    // Finds a character on a ring, trying the other case if it is not found.
+    fn find(ring: &[char], c: char) -> Option<usize> {
+        ring.iter()
+            .position(|&r| r == c)
+            .or_else(|| ring.iter().position(|&r| r == c.to_ascii_uppercase()))
+            .or_else(|| ring.iter().position(|&r| r == c.to_ascii_lowercase()))
+    }
+
+    // The number of places the outer ring is ahead of the inner ring when the
+    // index letter sits under the setting.
+    fn offset(&self, setting: char) -> usize {
+        let n = self.outer.len();
+        let outer = self.outer.iter().position(|&c| c == setting).unwrap();
+        let inner = self.inner.iter().position(|&c| c == self.index).unwrap();
+        (outer + n - inner) % n
+    }
+
+    // Moves a position between the rings, like a caesar shift between two
+    // alphabets.
+    fn shift(&self, position: usize, offset: usize, outward: bool) -> usize {
+        let n = self.outer.len();
+        if outward {
+            (position + offset) % n
+        } else {
+            (position + n - offset) % n
+        }
+    }
+
+    // Turns the inner ring when a period of characters has been enciphered.
+    fn turn(&self, offset: usize, count: usize) -> usize {
+        match self.rotation {
+            Rotation::Periodic { period, step } if count.is_multiple_of(period) => {
+                (offset + step) % self.outer.len()
+            }
+            _ => offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alberti, Rotation, MOBILIS, STABILIS};
+
+    #[test]
+    fn unequal_rings() {
+        assert!(Alberti::new("ABC", "ab", 'a', 'A', Rotation::Fixed).is_err());
+    }
+
+    #[test]
+    fn repeated_ring_character() {
+        assert!(Alberti::new("ABA", "abc", 'a', 'A', Rotation::Fixed).is_err());
+    }
+
+    #[test]
+    fn shared_ring_character() {
+        assert!(Alberti::new("ABC", "abC", 'a', 'A', Rotation::Fixed).is_err());
+    }
+
+    #[test]
+    fn index_not_on_inner_ring() {
+        assert!(Alberti::new(STABILIS, MOBILIS, 'j', 'A', Rotation::Fixed).is_err());
+    }
+
+    #[test]
+    fn signal_not_on_outer_ring() {
+        let signals = Rotation::Signals(vec![(3, 'H')]);
+        assert!(Alberti::new(STABILIS, MOBILIS, 'k', 'A', signals).is_err());
+    }
+
+    #[test]
+    fn zero_period() {
+        let periodic = Rotation::Periodic { period: 0, step: 1 };
+        assert!(Alberti::new(STABILIS, MOBILIS, 'k', 'A', periodic).is_err());
+    }
+
+    #[test]
+    fn encipher_fixed() {
+        let a = Alberti::new(STABILIS, MOBILIS, 'g', 'A', Rotation::Fixed).unwrap();
+        assert_eq!("lgk, np", a.encipher("cab, de").unwrap());
+    }
+
+    #[test]
+    fn letter_not_on_outer_ring() {
+        let a = Alberti::new(STABILIS, MOBILIS, 'g', 'A', Rotation::Fixed).unwrap();
+        assert!(a.encipher("hat").is_err());
+    }
+
+    #[test]
+    fn encipher_periodic() {
+        let periodic = Rotation::Periodic { period: 1, step: 1 };
+        let a = Alberti::new(STABILIS, MOBILIS, 'g', 'A', periodic).unwrap();
+        assert_eq!("gee", a.encipher("AAB").unwrap());
+    }
+
+    #[test]
+    fn decipher_periodic() {
+        let periodic = Rotation::Periodic { period: 1, step: 1 };
+        let a = Alberti::new(STABILIS, MOBILIS, 'g', 'A', periodic).unwrap();
+        assert_eq!("AAB", a.decipher("gee").unwrap());
+    }
+
+    #[test]
+    fn encipher_signals() {
+        let signals = Rotation::Signals(vec![(0, 'B'), (2, 'D')]);
+        let a = Alberti::new(STABILIS, MOBILIS, 'k', 'A', signals).unwrap();
+        assert_eq!("BlgDe", a.encipher("CAB").unwrap());
+    }
+
+    #[test]
+    fn decipher_signals() {
+        let a = Alberti::new(STABILIS, MOBILIS, 'k', 'A', Rotation::Signals(Vec::new())).unwrap();
+        assert_eq!("CAB", a.decipher("BlgDe").unwrap());
+    }
+
+    #[test]
+    fn decipher_without_signals_rejects_outer_ring() {
+        let a = Alberti::new(STABILIS, MOBILIS, 'k', 'A', Rotation::Fixed).unwrap();
+        assert!(a.decipher("BlgDe").is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use ciphers::columnar::validate_key;
+use common::grid::Grid;
+
+/// AMSCO Transposition Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Amsco {
+    order: Vec<usize>,
+    start: usize,
+}
+
+impl Amsco {
+    /// Initializes an AMSCO transposition cipher with a supplied key and the size
+    /// of the first cell.
+    ///
+    /// The message is written by rows into cells that alternate between one and
+    /// two characters, with each row starting with the other size from the row
+    /// above. It is read out by columns in the alphabetical order of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::amsco::Amsco;
+    ///
+    /// let a = Amsco::new("312", 1).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key is empty or not alphanumeric, or the first
+    /// cell size is not 1 or 2.
+    ///
+    pub fn new(key: &str, start: usize) -> Result<Self, String> {
+        if start != 1 && start != 2 {
+            return Err(String::from("The first cell must hold 1 or 2 characters"));
+        }
+        Ok(Amsco {
+            order: validate_key(key)?,
+            start,
+        })
+    }
+
+    /// Enciphers a message with an AMSCO transposition cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::amsco::Amsco;
+    ///
+    /// let a = Amsco::new("312", 1).unwrap();
+    /// assert_eq!("BCGDHIAEFJ", a.encipher("ABCDEFGHIJ").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let text = plaintext.chars().collect::<Vec<char>>();
+        Ok(Grid::transpose(&text, &self.order(text.len())))
+    }
+
+    /// Deciphers a message with an AMSCO transposition cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::amsco::Amsco;
+    ///
+    /// let a = Amsco::new("312", 1).unwrap();
+    /// assert_eq!("ABCDEFGHIJ", a.decipher("BCGDHIAEFJ").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let text = ciphertext.chars().collect::<Vec<char>>();
+        Ok(Grid::restore(&text, &self.order(text.len())))
+    }
+
+    // Calculates the order the characters of the text are read out in.
+    fn order(&self, length: usize) -> Vec<usize> {
+        let start = self.start;
+        let grid = Grid::with_sizes(self.order.len(), length, |row, col| {
+            if (row + col) % 2 == 0 {
+                start
+            } else {
+                3 - start
+            }
+        });
+        let groups = self.order.iter().map(|&c| vec![c]).collect::<Vec<_>>();
+        grid.read_columns(&groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Amsco;
+
+    #[test]
+    fn valid_key() {
+        assert!(Amsco::new("41325", 2).is_ok());
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(Amsco::new("4132.", 1).is_err());
+    }
+
+    #[test]
+    fn invalid_start() {
+        assert!(Amsco::new("41325", 3).is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let a = Amsco::new("312", 1).unwrap();
+        assert_eq!("BCGDHIAEFJ", a.encipher("ABCDEFGHIJ").unwrap());
+    }
+
+    #[test]
+    fn decipher() {
+        let a = Amsco::new("312", 1).unwrap();
+        assert_eq!("ABCDEFGHIJ", a.decipher("BCGDHIAEFJ").unwrap());
+    }
+
+    #[test]
+    fn digraph_first() {
+        let a = Amsco::new("312", 2).unwrap();
+        assert_eq!("CGHDEIABFJ", a.encipher("ABCDEFGHIJ").unwrap());
+    }
+
+    #[test]
+    fn round_trip() {
+        let a = Amsco::new("41325", 2).unwrap();
+        let plaintext = "Incomplete columnar with alternating single letters and digraphs";
+        assert_eq!(
+            plaintext,
+            a.decipher(&a.encipher(plaintext).unwrap()).unwrap()
+        );
+    }
+}
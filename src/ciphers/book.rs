@@ -0,0 +1,315 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// How a book cipher refers to the words of its key document.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Reference {
+    /// Each letter is the number of a word starting with that letter, counting
+    /// the words of the whole document from 1, as in the Beale ciphers.
+    Beale,
+    /// Each word is written as the page, line and word it is found at, such as
+    /// 12.4.7. Pages are separated by form feeds and counted from 1, as are the
+    /// lines of a page and the words of a line.
+    PageLineWord,
+}
+
+/// Book Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct BookCipher {
+    words: Vec<(usize, usize, usize, String)>,
+    reference: Reference,
+    seed: u64,
+}
+
+impl BookCipher {
+    /// Initializes a book cipher with a key document, the kind of references to
+    /// use, and a seed for choosing between the words that could be used.
+    ///
+    /// Words are compared by their letters and digits without case, so
+    /// punctuation in the document is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::book::{BookCipher, Reference};
+    ///
+    /// let b = BookCipher::new("It was a bright cold day in April", Reference::Beale, 7).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the document has no words.
+    ///
+    pub fn new(document: &str, reference: Reference, seed: u64) -> Result<Self, String> {
+        let mut words = Vec::new();
+        for (p, page) in document.split('\x0c').enumerate() {
+            for (l, line) in page.lines().enumerate() {
+                let line = line
+                    .split_whitespace()
+                    .map(BookCipher::normalize)
+                    .filter(|w| !w.is_empty());
+                for (w, word) in line.enumerate() {
+                    words.push((p + 1, l + 1, w + 1, word));
+                }
+            }
+        }
+        if words.is_empty() {
+            return Err(String::from("Document must have words"));
+        }
+
+        Ok(BookCipher {
+            words,
+            reference,
+            seed,
+        })
+    }
+
+    /// Enciphers a message with a book cipher.
+    ///
+    /// With Beale references each letter is enciphered and the numbers are
+    /// separated by commas, while with page, line and word references each word is
+    /// enciphered and the references are separated by spaces. When several words
+    /// could be used one is chosen at random.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::book::{BookCipher, Reference};
+    ///
+    /// let b = BookCipher::new("It was a bright cold day in April", Reference::Beale, 7).unwrap();
+    /// assert_eq!("5, 1, 8", b.encipher("Cia").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if a letter or word of the message is not in the
+    /// document.
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut references = Vec::new();
+
+        match self.reference {
+            Reference::Beale => {
+                for c in plaintext.chars().filter(|c| c.is_alphanumeric()) {
+                    let c = c.to_lowercase().next().unwrap();
+                    let candidates = self
+                        .words
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, w)| w.3.starts_with(c))
+                        .map(|(i, _)| (i + 1).to_string())
+                        .collect::<Vec<String>>();
+                    references.push(BookCipher::choose(&mut rng, candidates, &c.to_string())?);
+                }
+                Ok(references.join(", "))
+            }
+            Reference::PageLineWord => {
+                for word in plaintext.split_whitespace().map(BookCipher::normalize) {
+                    if word.is_empty() {
+                        continue;
+                    }
+                    let candidates = self
+                        .words
+                        .iter()
+                        .filter(|w| w.3 == word)
+                        .map(|w| format!("{}.{}.{}", w.0, w.1, w.2))
+                        .collect::<Vec<String>>();
+                    references.push(BookCipher::choose(&mut rng, candidates, &word)?);
+                }
+                Ok(references.join(" "))
+            }
+        }
+    }
+
+    /// Deciphers a message with a book cipher.
+    ///
+    /// Beale references are deciphered to the upper case letters they stand for,
+    /// and page, line and word references to the lower case words separated by
+    /// spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::book::{BookCipher, Reference};
+    ///
+    /// let b = BookCipher::new("It was a bright cold day in April", Reference::Beale, 7).unwrap();
+    /// assert_eq!("CIA", b.decipher("5, 1, 8").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if a reference is not written as the cipher's references
+    /// are, or is outside the document.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let references = ciphertext
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|r| !r.is_empty());
+
+        match self.reference {
+            Reference::Beale => {
+                let mut plaintext = String::new();
+                for r in references {
+                    let n = r
+                        .parse::<usize>()
+                        .map_err(|_| format!("Reference '{}' is not a word number", r))?;
+                    if n == 0 || n > self.words.len() {
+                        return Err(format!("Reference {} is out of range", r));
+                    }
+                    let first = self.words[n - 1].3.chars().next().unwrap();
+                    plaintext.extend(first.to_uppercase());
+                }
+                Ok(plaintext)
+            }
+            Reference::PageLineWord => {
+                let mut words = Vec::new();
+                for r in references {
+                    let n = r
+                        .split('.')
+                        .map(|n| n.parse::<usize>())
+                        .collect::<Result<Vec<usize>, _>>()
+                        .ok()
+                        .filter(|n| n.len() == 3)
+                        .ok_or_else(|| format!("Reference '{}' is not a page.line.word", r))?;
+                    match self
+                        .words
+                        .iter()
+                        .find(|w| (w.0, w.1, w.2) == (n[0], n[1], n[2]))
+                    {
+                        Some(w) => words.push(w.3.clone()),
+                        None => return Err(format!("Reference {} is out of range", r)),
+                    }
+                }
+                Ok(words.join(" "))
+            }
+        }
+    }
+
+    // Picks one of the references that could be used at random.
+    fn choose(rng: &mut StdRng, candidates: Vec<String>, text: &str) -> Result<String, String> {
+        if candidates.is_empty() {
+            return Err(format!("'{}' is not in the document", text));
+        }
+        let i = rng.gen_range(0..candidates.len());
+        Ok(candidates[i].clone())
+    }
+
+    // The letters and digits of a word in lower case.
+    fn normalize(word: &str) -> String {
+        word.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(|c| c.to_lowercase())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BookCipher, Reference};
+
+    // The opening of the Declaration of Independence, the key to the second Beale
+    // cipher.
+    const DECLARATION: &str = "When in the course of human events, it becomes necessary \
+        for one people to dissolve the political bands which have connected them with \
+        another, and to assume among the powers of the earth, the separate and equal \
+        station to which the laws of nature and of nature's God entitle them, a decent \
+        respect to the opinions of mankind requires that they should declare the causes \
+        which impel them to the separation.";
+
+    const BOOK: &str = "The quick brown fox\njumps over the lazy dog\n\x0c\
+        The dog sleeps\nthe fox runs away";
+
+    #[test]
+    fn empty_document() {
+        assert!(BookCipher::new(" \n\x0c ,", Reference::Beale, 0).is_err());
+    }
+
+    #[test]
+    fn beale_decipher() {
+        // The start of "deposited" in the second Beale cipher.
+        let b = BookCipher::new(DECLARATION, Reference::Beale, 0).unwrap();
+        assert_eq!("DEPOS", b.decipher("52, 49, 17, 31, 62").unwrap());
+    }
+
+    #[test]
+    fn beale_out_of_range() {
+        let b = BookCipher::new(DECLARATION, Reference::Beale, 0).unwrap();
+        assert_eq!(
+            Err(String::from("Reference 647 is out of range")),
+            b.decipher("31, 62, 647, 22")
+        );
+        assert!(b.decipher("0").is_err());
+        assert!(b.decipher("31, x").is_err());
+    }
+
+    #[test]
+    fn beale_round_trip() {
+        let b = BookCipher::new(DECLARATION, Reference::Beale, 42).unwrap();
+        let ciphertext = b.encipher("A secret near Bedford").unwrap();
+        assert_eq!("ASECRETNEARBEDFORD", b.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn beale_missing_letter() {
+        let b = BookCipher::new(DECLARATION, Reference::Beale, 0).unwrap();
+        assert!(b.encipher("zebra").is_err());
+    }
+
+    #[test]
+    fn seed_chooses_candidates() {
+        let plaintext = "the the the the the the the the";
+        let ciphertexts = (0..4)
+            .map(|seed| {
+                BookCipher::new(DECLARATION, Reference::Beale, seed)
+                    .unwrap()
+                    .encipher(plaintext)
+                    .unwrap()
+            })
+            .collect::<Vec<String>>();
+        assert!(ciphertexts.iter().any(|c| *c != ciphertexts[0]));
+
+        let b = BookCipher::new(DECLARATION, Reference::Beale, 2).unwrap();
+        assert_eq!(ciphertexts[2], b.encipher(plaintext).unwrap());
+    }
+
+    #[test]
+    fn page_line_word_encipher() {
+        let b = BookCipher::new(BOOK, Reference::PageLineWord, 0).unwrap();
+        let ciphertext = b.encipher("Quick, sleeps away").unwrap();
+        assert_eq!("1.1.2 2.1.3 2.2.4", ciphertext);
+    }
+
+    #[test]
+    fn page_line_word_decipher() {
+        let b = BookCipher::new(BOOK, Reference::PageLineWord, 0).unwrap();
+        assert_eq!(
+            "the lazy fox runs",
+            b.decipher("2.2.1 1.2.4 1.1.4 2.2.3").unwrap()
+        );
+    }
+
+    #[test]
+    fn page_line_word_round_trip() {
+        let b = BookCipher::new(BOOK, Reference::PageLineWord, 9).unwrap();
+        let ciphertext = b.encipher("The dog jumps over the fox").unwrap();
+        assert_eq!(
+            "the dog jumps over the fox",
+            b.decipher(&ciphertext).unwrap()
+        );
+    }
+
+    #[test]
+    fn page_line_word_errors() {
+        let b = BookCipher::new(BOOK, Reference::PageLineWord, 0).unwrap();
+        assert!(b.encipher("The cat").is_err());
+        assert_eq!(
+            Err(String::from("Reference 2.3.1 is out of range")),
+            b.decipher("1.1.1 2.3.1")
+        );
+        assert!(b.decipher("1.1").is_err());
+    }
+}
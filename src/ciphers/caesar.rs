@@ -1,9 +1,13 @@
+use ciphers::progressive::ShiftCipher;
+use common::ALPHABET;
+
 /// Caesar Cipher
 ///
-/// The struct is generated through the new() function.
+/// The struct is generated through the new() or with_alphabet() functions.
 ///
 pub struct Caesar {
     rot: u8,
+    alphabet: Vec<char>,
 }
 
 impl Caesar {
@@ -25,10 +29,48 @@ impl Caesar {
         if !(1..=26).contains(&rot) {
             Err(String::from("Rotation must be between 1 through 26"))
         } else {
-            Ok(Caesar { rot })
+            Ok(Caesar {
+                rot,
+                alphabet: ALPHABET.chars().collect(),
+            })
         }
     }
 
+    /// Initializes a keyed caesar cipher that shifts letters within a mixed
+    /// alphabet, such as one built by the `keygen` module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::caesar::Caesar;
+    /// use kryptos::keygen;
+    ///
+    /// let alphabet = keygen::keyword_mixed("kryptos").unwrap();
+    /// let c = Caesar::with_alphabet(3, &alphabet).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the rotation is not between 1 and 26 or the alphabet
+    /// is not 26 unique alphabetic characters.
+    ///
+    pub fn with_alphabet(rot: u8, alphabet: &str) -> Result<Self, String> {
+        let mut c = Caesar::new(rot)?;
+        let alphabet = alphabet.to_ascii_uppercase();
+        if alphabet.chars().count() != 26 {
+            return Err(String::from("Alphabet is not the correct length"));
+        }
+        if !alphabet.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(String::from("Alphabet must be alphabetic"));
+        }
+        if ALPHABET.chars().any(|c| !alphabet.contains(c)) {
+            return Err(String::from("Alphabet must be unique"));
+        }
+
+        c.alphabet = alphabet.chars().collect();
+        Ok(c)
+    }
+
     /// Enciphers a message with a caesar cipher.
     ///
     /// # Examples
@@ -38,10 +80,14 @@ impl Caesar {
     ///
     /// let c = Caesar::new(13).unwrap();
     /// assert_eq!("guvf vf n frperg", c.encipher("this is a secret").unwrap());
+    ///
+    /// let alphabet = "KRYPTOSABCDEFGHIJLMNQUVWXZ";
+    /// let c = Caesar::with_alphabet(3, alphabet).unwrap();
+    /// assert_eq!("Lhqqb", c.encipher("Hello").unwrap());
     /// ```
     ///
     pub fn encipher(&self, plaintext: &str) -> Result<String, &'static str> {
-        Caesar::shift(plaintext, self.rot)
+        self.shift(plaintext, self.rot)
     }
 
     /// Deciphers a message with a caesar cipher.
@@ -57,27 +103,48 @@ impl Caesar {
     ///
     pub fn decipher(&self, ciphertext: &str) -> Result<String, &'static str> {
         let rot = 26 - self.rot;
-        Caesar::shift(ciphertext, rot)
+        self.shift(ciphertext, rot)
     }
 
-    // Shifts letters in a message by a given rotation.
+    // Shifts letters in a message by a given rotation within the alphabet.
     //
-    fn shift(text: &str, rot: u8) -> Result<String, &'static str> {
+    fn shift(&self, text: &str, rot: u8) -> Result<String, &'static str> {
         Ok(text
             .chars()
-            .map(|c| match c as u8 {
-                65..=90 => (((c as u8 - 65 + rot) % 26) + 65) as char,
-                97..=122 => (((c as u8 - 97 + rot) % 26) + 97) as char,
-                _ => c,
+            .map(|c| {
+                let upper = c.to_ascii_uppercase();
+                match self.alphabet.iter().position(|&a| a == upper) {
+                    Some(index) if c.is_ascii_alphabetic() => {
+                        let shifted = self.alphabet[(index + rot as usize) % 26];
+                        if c.is_ascii_lowercase() {
+                            shifted.to_ascii_lowercase()
+                        } else {
+                            shifted
+                        }
+                    }
+                    _ => c,
+                }
             })
             .collect::<String>())
     }
 }
 
+impl ShiftCipher for Caesar {
+    fn shifts(&self) -> Result<Vec<u8>, String> {
+        Ok(vec![self.rot % 26])
+    }
+
+    fn alphabet(&self) -> Vec<char> {
+        self.alphabet.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Caesar;
 
+    const ALPHABET: &str = "KRYPTOSABCDEFGHIJLMNQUVWXZ";
+
     #[test]
     fn encipher() {
         let c = Caesar::new(13).unwrap();
@@ -117,6 +184,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn keyed_encipher() {
+        let c = Caesar::with_alphabet(3, ALPHABET).unwrap();
+        assert_eq!("Lhqqb, R", c.encipher("Hello, X").unwrap());
+    }
+
+    #[test]
+    fn keyed_decipher() {
+        let c = Caesar::with_alphabet(3, ALPHABET).unwrap();
+        assert_eq!("Hello, X", c.decipher("Lhqqb, R").unwrap());
+    }
+
+    #[test]
+    fn keyed_duplicate_letter() {
+        assert!(Caesar::with_alphabet(3, "KRYPTOSABCDEFGHIJLMNQUVWXK").is_err());
+    }
+
+    #[test]
+    fn keyed_too_small_alphabet() {
+        assert!(Caesar::with_alphabet(3, "KRYPTOS").is_err());
+    }
+
     #[test]
     fn too_low_rotation() {
         assert!(Caesar::new(0).is_err());
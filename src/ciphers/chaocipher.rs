@@ -0,0 +1,211 @@
+use common::ALPHABET;
+
+/// Chaocipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Chaocipher {
+    left: Vec<char>,
+    right: Vec<char>,
+}
+
+impl Chaocipher {
+    /// Initializes a chaocipher with the starting left (ciphertext) and right
+    /// (plaintext) alphabets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::chaocipher::Chaocipher;
+    ///
+    /// let c = Chaocipher::new(
+    ///     "HXUCZVAMDSLKPEFJRIGTWOBNYQ",
+    ///     "PTLNBQDEOYSFAVZKGJRIHWXUMC",
+    /// ).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if either alphabet is not 26 unique letters.
+    ///
+    pub fn new(left: &str, right: &str) -> Result<Self, String> {
+        Ok(Chaocipher {
+            left: Chaocipher::validate(left)?,
+            right: Chaocipher::validate(right)?,
+        })
+    }
+
+    /// Enciphers a message with a chaocipher.
+    ///
+    /// The case of letters is kept and other characters are left unchanged without
+    /// permuting the alphabets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::chaocipher::Chaocipher;
+    ///
+    /// let c = Chaocipher::new(
+    ///     "HXUCZVAMDSLKPEFJRIGTWOBNYQ",
+    ///     "PTLNBQDEOYSFAVZKGJRIHWXUMC",
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     "OAHQHCNYNXTSZJRRHJBYHQKSOUJY",
+    ///     c.encipher("WELLDONEISBETTERTHANWELLSAID").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        Ok(self.transpose(plaintext, false))
+    }
+
+    /// Deciphers a message with a chaocipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::chaocipher::Chaocipher;
+    ///
+    /// let c = Chaocipher::new(
+    ///     "HXUCZVAMDSLKPEFJRIGTWOBNYQ",
+    ///     "PTLNBQDEOYSFAVZKGJRIHWXUMC",
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     "WELLDONEISBETTERTHANWELLSAID",
+    ///     c.decipher("OAHQHCNYNXTSZJRRHJBYHQKSOUJY").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        Ok(self.transpose(ciphertext, true))
+    }
+
+    // Substitutes each letter between the alphabets, permuting them after each.
+    fn transpose(&self, text: &str, decipher: bool) -> String {
+        let mut left = self.left.clone();
+        let mut right = self.right.clone();
+
+        text.chars()
+            .map(|c| {
+                if !c.is_ascii_alphabetic() {
+                    return c;
+                }
+                let upper = c.to_ascii_uppercase();
+                let (from, to) = if decipher {
+                    (&left, &right)
+                } else {
+                    (&right, &left)
+                };
+                let index = from.iter().position(|&l| l == upper).unwrap();
+                let result = to[index];
+
+                Chaocipher::permute_left(&mut left, index);
+                Chaocipher::permute_right(&mut right, index);
+
+                if c.is_ascii_lowercase() {
+                    result.to_ascii_lowercase()
+                } else {
+                    result
+                }
+            })
+            .collect()
+    }
+
+    // Brings the ciphertext letter to the zenith, then moves the letter after it
+    // down to the nadir.
+    fn permute_left(alphabet: &mut [char], index: usize) {
+        alphabet.rotate_left(index);
+        alphabet[1..14].rotate_left(1);
+    }
+
+    // Brings the letter after the plaintext letter to the zenith, then moves the
+    // third letter down to the nadir.
+    fn permute_right(alphabet: &mut [char], index: usize) {
+        alphabet.rotate_left((index + 1) % 26);
+        alphabet[2..14].rotate_left(1);
+    }
+
+    // Checks an alphabet contains each letter once and converts it to uppercase.
+    fn validate(alphabet: &str) -> Result<Vec<char>, String> {
+        let alphabet = alphabet.to_ascii_uppercase().chars().collect::<Vec<char>>();
+        if alphabet.len() != 26 {
+            return Err(String::from("Alphabet is not the correct length"));
+        }
+        for c in ALPHABET.chars() {
+            if !alphabet.contains(&c) {
+                return Err(String::from("Alphabet must contain each letter once"));
+            }
+        }
+        Ok(alphabet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Chaocipher;
+
+    const LEFT: &str = "HXUCZVAMDSLKPEFJRIGTWOBNYQ";
+    const RIGHT: &str = "PTLNBQDEOYSFAVZKGJRIHWXUMC";
+
+    #[test]
+    fn valid_alphabets() {
+        assert!(Chaocipher::new(LEFT, RIGHT).is_ok());
+    }
+
+    #[test]
+    fn non_unique_alphabet() {
+        assert!(Chaocipher::new("HXUCZVAMDSLKPEFJRIGTWOBNYH", RIGHT).is_err());
+    }
+
+    #[test]
+    fn too_small_alphabet() {
+        assert!(Chaocipher::new(LEFT, "PTLNB").is_err());
+    }
+
+    #[test]
+    fn left_permutation() {
+        let mut left = LEFT.chars().collect::<Vec<char>>();
+        Chaocipher::permute_left(&mut left, 21);
+        assert_eq!(
+            "ONYQHXUCZVAMDBSLKPEFJRIGTW",
+            left.iter().collect::<String>()
+        );
+    }
+
+    #[test]
+    fn right_permutation() {
+        let mut right = RIGHT.chars().collect::<Vec<char>>();
+        Chaocipher::permute_right(&mut right, 21);
+        assert_eq!(
+            "XUCPTLNBQDEOYMSFAVZKGJRIHW",
+            right.iter().collect::<String>()
+        );
+    }
+
+    #[test]
+    fn encipher() {
+        let c = Chaocipher::new(LEFT, RIGHT).unwrap();
+        assert_eq!(
+            "OAHQHCNYNXTSZJRRHJBYHQKSOUJY",
+            c.encipher("WELLDONEISBETTERTHANWELLSAID").unwrap()
+        );
+    }
+
+    #[test]
+    fn decipher() {
+        let c = Chaocipher::new(LEFT, RIGHT).unwrap();
+        assert_eq!(
+            "WELLDONEISBETTERTHANWELLSAID",
+            c.decipher("OAHQHCNYNXTSZJRRHJBYHQKSOUJY").unwrap()
+        );
+    }
+
+    #[test]
+    fn with_punctuation() {
+        let c = Chaocipher::new(LEFT, RIGHT).unwrap();
+        assert_eq!(
+            "Oah qhcn, yn xtsz!",
+            c.encipher("Wel ldon, ei sbet!").unwrap()
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use common::column_order;
+use common::grid::Grid;
+
+/// Columnar Transposition Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Columnar {
+    order: Vec<usize>,
+}
+
+impl Columnar {
+    /// Initializes a columnar transposition cipher with a supplied key.
+    ///
+    /// The message is written by rows under the key and read out by columns in the
+    /// alphabetical order of the key, with repeated letters read left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::columnar::Columnar;
+    ///
+    /// let c = Columnar::new("zebras").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key is empty or not alphanumeric.
+    ///
+    pub fn new(key: &str) -> Result<Self, String> {
+        Ok(Columnar {
+            order: validate_key(key)?,
+        })
+    }
+
+    /// Enciphers a message with a columnar transposition cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::columnar::Columnar;
+    ///
+    /// let c = Columnar::new("zebras").unwrap();
+    /// assert_eq!(
+    ///     "EVLNEACDTKESEAQROFOJDEECUWIREE",
+    ///     c.encipher("WEAREDISCOVEREDFLEEATONCEQKJEU").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let text = plaintext.chars().collect::<Vec<char>>();
+        Ok(Grid::transpose(&text, &self.order(text.len())))
+    }
+
+    /// Deciphers a message with a columnar transposition cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::columnar::Columnar;
+    ///
+    /// let c = Columnar::new("zebras").unwrap();
+    /// assert_eq!(
+    ///     "WEAREDISCOVEREDFLEEATONCEQKJEU",
+    ///     c.decipher("EVLNEACDTKESEAQROFOJDEECUWIREE").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let text = ciphertext.chars().collect::<Vec<char>>();
+        Ok(Grid::restore(&text, &self.order(text.len())))
+    }
+
+    // Calculates the order the characters of the text are read out in.
+    fn order(&self, length: usize) -> Vec<usize> {
+        let groups = self.order.iter().map(|&c| vec![c]).collect::<Vec<_>>();
+        Grid::new(self.order.len(), length).read_columns(&groups)
+    }
+}
+
+/// Disrupted Columnar Transposition Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct DisruptedColumnar {
+    order: Vec<usize>,
+}
+
+impl DisruptedColumnar {
+    /// Initializes a disrupted columnar transposition cipher with a supplied key.
+    ///
+    /// The grid is disrupted by triangular areas. The first starts in the column
+    /// read first and runs to the right edge, starting one column further right on
+    /// each row until a row is left whole. The next area then starts in the column
+    /// read second, and so on. The message is written by rows into the cells
+    /// outside the areas, then into the areas, and read out by columns in the
+    /// alphabetical order of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::columnar::DisruptedColumnar;
+    ///
+    /// let d = DisruptedColumnar::new("dacb").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key is empty or not alphanumeric.
+    ///
+    pub fn new(key: &str) -> Result<Self, String> {
+        Ok(DisruptedColumnar {
+            order: validate_key(key)?,
+        })
+    }
+
+    /// Enciphers a message with a disrupted columnar transposition cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::columnar::DisruptedColumnar;
+    ///
+    /// let d = DisruptedColumnar::new("dacb").unwrap();
+    /// assert_eq!("ICEHKMNJLFABDG", d.encipher("ABCDEFGHIJKLMN").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let text = plaintext.chars().collect::<Vec<char>>();
+        Ok(Grid::transpose(&text, &self.order(text.len())))
+    }
+
+    /// Deciphers a message with a disrupted columnar transposition cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::columnar::DisruptedColumnar;
+    ///
+    /// let d = DisruptedColumnar::new("dacb").unwrap();
+    /// assert_eq!("ABCDEFGHIJKLMN", d.decipher("ICEHKMNJLFABDG").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let text = ciphertext.chars().collect::<Vec<char>>();
+        Ok(Grid::restore(&text, &self.order(text.len())))
+    }
+
+    // Calculates the order the characters of the text are read out in.
+    fn order(&self, length: usize) -> Vec<usize> {
+        let width = self.order.len();
+        let mut grid = Grid::new(width, length);
+
+        let mut open = Vec::new();
+        let mut disrupted = Vec::new();
+        let mut row = 0;
+        for &start in self.order.iter().cycle() {
+            for edge in start..=width {
+                if row == grid.rows() {
+                    break;
+                }
+                for col in 0..width {
+                    if col < edge {
+                        open.push((row, col));
+                    } else {
+                        disrupted.push((row, col));
+                    }
+                }
+                row += 1;
+            }
+            if row == grid.rows() {
+                break;
+            }
+        }
+        open.retain(|&(row, col)| grid.contains(row, col));
+        disrupted.retain(|&(row, col)| grid.contains(row, col));
+        open.extend(disrupted);
+        grid.write(&open);
+
+        let groups = self.order.iter().map(|&c| vec![c]).collect::<Vec<_>>();
+        grid.read_columns(&groups)
+    }
+}
+
+// Checks a transposition key and calculates its column order.
+pub(crate) fn validate_key(key: &str) -> Result<Vec<usize>, String> {
+    if key.is_empty() {
+        return Err(String::from("Key must not be empty"));
+    }
+    if !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(String::from("Key must be alphanumeric"));
+    }
+    Ok(column_order(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Columnar, DisruptedColumnar};
+
+    #[test]
+    fn valid_key() {
+        assert!(Columnar::new("zebras").is_ok());
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(Columnar::new("zeb-ras").is_err());
+    }
+
+    #[test]
+    fn empty_key() {
+        assert!(DisruptedColumnar::new("").is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let c = Columnar::new("zebras").unwrap();
+        assert_eq!(
+            "EVLNEACDTKESEAQROFOJDEECUWIREE",
+            c.encipher("WEAREDISCOVEREDFLEEATONCEQKJEU").unwrap()
+        );
+    }
+
+    #[test]
+    fn decipher() {
+        let c = Columnar::new("zebras").unwrap();
+        assert_eq!(
+            "WEAREDISCOVEREDFLEEATONCEQKJEU",
+            c.decipher("EVLNEACDTKESEAQROFOJDEECUWIREE").unwrap()
+        );
+    }
+
+    #[test]
+    fn irregular_last_row() {
+        let c = Columnar::new("zebras").unwrap();
+        assert_eq!(
+            "EVLNACDTESEAROFODEECWIREE",
+            c.encipher("WEAREDISCOVEREDFLEEATONCE").unwrap()
+        );
+        assert_eq!(
+            "WEAREDISCOVEREDFLEEATONCE",
+            c.decipher("EVLNACDTESEAROFODEECWIREE").unwrap()
+        );
+    }
+
+    #[test]
+    fn disrupted_encipher() {
+        let d = DisruptedColumnar::new("dacb").unwrap();
+        assert_eq!("ICEHKMNJLFABDG", d.encipher("ABCDEFGHIJKLMN").unwrap());
+    }
+
+    #[test]
+    fn disrupted_decipher() {
+        let d = DisruptedColumnar::new("dacb").unwrap();
+        assert_eq!("ABCDEFGHIJKLMN", d.decipher("ICEHKMNJLFABDG").unwrap());
+    }
+
+    #[test]
+    fn disrupted_multiple_areas() {
+        let d = DisruptedColumnar::new("4213").unwrap();
+        let plaintext = "We are discovered, flee at once!";
+        assert_eq!(
+            plaintext,
+            d.decipher(&d.encipher(plaintext).unwrap()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,572 @@
+use ciphers::rotor::{step_with_gears, step_with_pawls, Pairs, Rotor, RotorSpec};
+
+/// The entry wheel of the military machines, wired straight through.
+pub const STRAIGHT: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+/// The entry wheel of the commercial machines, wired in keyboard order.
+pub const QWERTZ: &str = "QWERTZUIOASDFGHJKPYXCVBNML";
+
+/// Rotor I of the Enigma I and M3.
+pub const I: RotorSpec<'static> = rotor("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "Q");
+/// Rotor II of the Enigma I and M3.
+pub const II: RotorSpec<'static> = rotor("AJDKSIRUXBLHWTMCQGZNPYFVOE", "E");
+/// Rotor III of the Enigma I and M3.
+pub const III: RotorSpec<'static> = rotor("BDFHJLCPRTXVZNYEIWGAKMUSQO", "V");
+/// Rotor IV of the Enigma I and M3.
+pub const IV: RotorSpec<'static> = rotor("ESOVPZJAYQUIRHXLNFTGKDCMWB", "J");
+/// Rotor V of the Enigma I and M3.
+pub const V: RotorSpec<'static> = rotor("VZBRGITYUPSDNHLXAWMJQOFECK", "Z");
+/// Reflector A of the Enigma I.
+pub const UKW_A: &str = "EJMZALYXVBWFCRQUONTSPIKHGD";
+/// Reflector B of the Enigma I and M3.
+pub const UKW_B: &str = "YRUHQSLDPXNGOKMIEBFZCWVJAT";
+/// Reflector C of the Enigma I and M3.
+pub const UKW_C: &str = "FVPJIAOYEDRZXWGCTKUQSBNMHL";
+
+/// Rotor I of the Swiss Enigma K.
+pub const K_I: RotorSpec<'static> = rotor("PEZUOHXSCVFMTBGLRINQJWAYDK", "Y");
+/// Rotor II of the Swiss Enigma K.
+pub const K_II: RotorSpec<'static> = rotor("ZOUESYDKFWPCIQXHMVBLGNJRAT", "E");
+/// Rotor III of the Swiss Enigma K.
+pub const K_III: RotorSpec<'static> = rotor("EHRVXGAOBQUSIMZFLYNWKTPDJC", "N");
+/// The settable reflector of the Swiss Enigma K.
+pub const K_UKW: RotorSpec<'static> = rotor("IMETCGFRAYSQBZXWLHKDVUPOJN", "");
+
+/// Rotor I of the Railway Enigma.
+pub const RAILWAY_I: RotorSpec<'static> = rotor("JGDQOXUSCAMIFRVTPNEWKBLZYH", "N");
+/// Rotor II of the Railway Enigma.
+pub const RAILWAY_II: RotorSpec<'static> = rotor("NTZPSFBOKMWRCJDIVLAEYUXHGQ", "E");
+/// Rotor III of the Railway Enigma.
+pub const RAILWAY_III: RotorSpec<'static> = rotor("JVIUBHTCDYAKEQZPOSGXNRMWFL", "Y");
+/// The settable reflector of the Railway Enigma.
+pub const RAILWAY_UKW: RotorSpec<'static> = rotor("QYHOGNECVPUZTFDJAXWMKISRBL", "");
+
+/// Rotor I of the Abwehr Enigma G.
+pub const G_I: RotorSpec<'static> = rotor("DMTWSILRUYQNKFEJCAZBPGXOHV", "SUVWZABCEFGIKLOPQ");
+/// Rotor II of the Abwehr Enigma G.
+pub const G_II: RotorSpec<'static> = rotor("HQZGPJTMOBLNCIFDYAWVEUSRKX", "STVYZACDFGHKMNQ");
+/// Rotor III of the Abwehr Enigma G.
+pub const G_III: RotorSpec<'static> = rotor("UQNTLSZFMREHDPXKIBVYGJCWOA", "UWXAEFHKMNR");
+/// The settable and moving reflector of the Abwehr Enigma G.
+pub const G_UKW: RotorSpec<'static> = rotor("RULQMZJSYGOCETKWDAHNBXPVIF", "");
+
+// The wiring of the Uhr disc, from each of its 40 contacts on the a plug face to
+// the contact on the b plug face.
+const UHR: [usize; 40] = [
+    6, 31, 4, 29, 18, 39, 16, 25, 30, 23, 28, 1, 38, 11, 36, 37, 26, 27, 24, 21, 14, 3, 12, 17, 2,
+    7, 0, 33, 10, 35, 8, 5, 22, 19, 20, 13, 34, 15, 32, 9,
+];
+// The slot on the b plug face taken by the b plug of each cable.
+const UHR_B_SLOTS: [usize; 10] = [1, 4, 7, 9, 6, 3, 0, 2, 5, 8];
+
+const fn rotor(wiring: &'static str, notches: &'static str) -> RotorSpec<'static> {
+    RotorSpec {
+        wiring,
+        notches,
+        ring: 'A',
+        position: 'A',
+        reversed: false,
+    }
+}
+
+/// How the rotors are moved on before each letter.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stepping {
+    /// Pawls and notched rings, which double step the middle rotor.
+    Pawls,
+    /// The gear train of the Enigma G, which counts like an odometer and also
+    /// turns a settable reflector.
+    Gears,
+}
+
+/// The reflector of the machine.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Reflector<'a> {
+    /// A fixed reflector, written as the letter each letter is wired to.
+    Fixed(&'a str),
+    /// A reflector that can be set to a position and ring setting like a rotor,
+    /// as on the commercial machines.
+    Settable(RotorSpec<'a>),
+    /// The field rewirable reflector UKW-D, written as 13 pairs of letters such
+    /// as "AC BO ..." that together pair every letter.
+    Rewirable(&'a str),
+}
+
+/// The plugboard of the machine.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Plugboard<'a> {
+    /// Plugged pairs of letters such as "AB CD", or empty for no plugboard.
+    Pairs(&'a str),
+    /// The Uhr attachment with 10 cables, written as pairs of letters with the
+    /// a plug of each cable in the first letter and the b plug in the second, and
+    /// the setting of its dial from 0 to 39. At setting 0 it swaps each pair like
+    /// the plugboard, and it is only reciprocal at multiples of 4.
+    Uhr(&'a str, usize),
+}
+
+/// The setup of an Enigma machine.
+///
+/// The rotors are listed from left to right, so the last is the fast rotor.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnigmaConfig<'a> {
+    pub entry: &'a str,
+    pub rotors: [RotorSpec<'a>; 3],
+    pub reflector: Reflector<'a>,
+    pub plugboard: Plugboard<'a>,
+    pub stepping: Stepping,
+}
+
+/// Enigma Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Enigma {
+    entry: Vec<usize>,
+    rotors: Vec<Rotor>,
+    reflector: Rotor,
+    moving_reflector: bool,
+    plugboard: Steckers,
+    stepping: Stepping,
+}
+
+impl Enigma {
+    /// Initializes an Enigma machine from its configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::enigma::*;
+    ///
+    /// let e = Enigma::new(&EnigmaConfig {
+    ///     entry: STRAIGHT,
+    ///     rotors: [I, II, III],
+    ///     reflector: Reflector::Fixed(UKW_B),
+    ///     plugboard: Plugboard::Pairs(""),
+    ///     stepping: Stepping::Pawls,
+    /// }).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the entry wheel or a rotor is not wired to each letter
+    /// once or has a setting that is not a letter, the reflector does not pair
+    /// every letter with another, or the plugboard pairs a letter twice. An Uhr
+    /// must have 10 cables and a setting below 40.
+    ///
+    pub fn new(config: &EnigmaConfig) -> Result<Self, String> {
+        let entry = Rotor::new(&RotorSpec::new(config.entry, ""), STRAIGHT)
+            .map_err(|_| String::from("Entry wheel must be wired to each letter once"))?;
+
+        let wiring = match config.reflector {
+            Reflector::Fixed(wiring) => String::from(wiring),
+            Reflector::Settable(spec) => String::from(spec.wiring),
+            Reflector::Rewirable(pairs) => Pairs::new(pairs)?.wiring(),
+        };
+        Pairs::reflector(&wiring)?;
+        let reflector = match config.reflector {
+            Reflector::Settable(spec) => RotorSpec {
+                wiring: &wiring,
+                ..spec
+            },
+            _ => RotorSpec::new(&wiring, ""),
+        };
+        let moving_reflector = matches!(config.reflector, Reflector::Settable(_))
+            && config.stepping == Stepping::Gears;
+
+        Ok(Enigma {
+            entry: (0..26).map(|i| entry.backward(i)).collect(),
+            rotors: config
+                .rotors
+                .iter()
+                .map(|spec| Rotor::new(spec, STRAIGHT))
+                .collect::<Result<Vec<Rotor>, String>>()?,
+            reflector: Rotor::new(&reflector, STRAIGHT)?,
+            moving_reflector,
+            plugboard: Steckers::new(&config.plugboard)?,
+            stepping: config.stepping,
+        })
+    }
+
+    /// Enciphers a message with an Enigma machine.
+    ///
+    /// The case of letters is kept and other characters are left unchanged
+    /// without stepping the rotors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::enigma::*;
+    ///
+    /// let e = Enigma::new(&EnigmaConfig {
+    ///     entry: STRAIGHT,
+    ///     rotors: [I, II, III],
+    ///     reflector: Reflector::Fixed(UKW_B),
+    ///     plugboard: Plugboard::Pairs(""),
+    ///     stepping: Stepping::Pawls,
+    /// }).unwrap();
+    /// assert_eq!("BDZGO", e.encipher("AAAAA").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        Ok(self.crypt(plaintext))
+    }
+
+    /// Deciphers a message with an Enigma machine, which is its own inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::enigma::*;
+    ///
+    /// let e = Enigma::new(&EnigmaConfig {
+    ///     entry: STRAIGHT,
+    ///     rotors: [I, II, III],
+    ///     reflector: Reflector::Fixed(UKW_B),
+    ///     plugboard: Plugboard::Pairs(""),
+    ///     stepping: Stepping::Pawls,
+    /// }).unwrap();
+    /// assert_eq!("AAAAA", e.decipher("BDZGO").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        Ok(self.crypt(ciphertext))
+    }
+
+    // Steps the rotors for each letter and passes it through the plugboard,
+    // entry wheel and rotors to the reflector and back.
+    fn crypt(&self, text: &str) -> String {
+        let mut rotors = self.rotors.clone();
+        let mut reflector = self.reflector.clone();
+        text.chars()
+            .map(|c| {
+                if !c.is_ascii_alphabetic() {
+                    return c;
+                }
+                match self.stepping {
+                    Stepping::Pawls => step_with_pawls(&mut rotors),
+                    Stepping::Gears if self.moving_reflector => {
+                        let mut bank = vec![reflector.clone()];
+                        bank.extend(rotors.iter().cloned());
+                        step_with_gears(&mut bank);
+                        reflector = bank.remove(0);
+                        rotors = bank;
+                    }
+                    Stepping::Gears => step_with_gears(&mut rotors),
+                }
+
+                let letter = self.plugboard.forward(c.to_ascii_uppercase() as usize - 65);
+                let mut contact = self.entry[letter];
+                for rotor in rotors.iter().rev() {
+                    contact = rotor.forward(contact);
+                }
+                contact = reflector.forward(contact);
+                for rotor in &rotors {
+                    contact = rotor.backward(contact);
+                }
+                let letter = self.entry.iter().position(|&e| e == contact).unwrap();
+                let output = (self.plugboard.backward(letter) as u8 + 65) as char;
+
+                if c.is_ascii_lowercase() {
+                    output.to_ascii_lowercase()
+                } else {
+                    output
+                }
+            })
+            .collect()
+    }
+}
+
+// The plugboard, either plugged in pairs or through the Uhr.
+enum Steckers {
+    Pairs(Pairs),
+    Uhr { plugs: Vec<usize>, setting: usize },
+}
+
+impl Steckers {
+    fn new(plugboard: &Plugboard) -> Result<Steckers, String> {
+        match *plugboard {
+            Plugboard::Pairs(pairs) => Ok(Steckers::Pairs(Pairs::new(pairs)?)),
+            Plugboard::Uhr(pairs, setting) => {
+                Pairs::new(pairs)?;
+                if pairs.split_whitespace().count() != 10 {
+                    return Err(String::from("The Uhr must have 10 cables plugged in"));
+                }
+                if setting >= 40 {
+                    return Err(String::from("The Uhr setting must be below 40"));
+                }
+                let letters = pairs.to_ascii_uppercase().into_bytes();
+                let cables = letters.split(|&b| b == b' ').filter(|p| !p.is_empty());
+                let (a, b): (Vec<_>, Vec<_>) = cables.map(|p| (p[0], p[1])).unzip();
+                Ok(Steckers::Uhr {
+                    plugs: a
+                        .iter()
+                        .chain(b.iter())
+                        .map(|&l| (l - 65) as usize)
+                        .collect(),
+                    setting,
+                })
+            }
+        }
+    }
+
+    // Maps a letter on its way from the keyboard to the entry wheel.
+    fn forward(&self, letter: usize) -> usize {
+        match *self {
+            Steckers::Pairs(ref pairs) => pairs.swap(letter),
+            Steckers::Uhr { ref plugs, setting } => Steckers::uhr(plugs, setting, letter, true),
+        }
+    }
+
+    // Maps a letter on its way from the entry wheel to the lamps.
+    fn backward(&self, letter: usize) -> usize {
+        match *self {
+            Steckers::Pairs(ref pairs) => pairs.swap(letter),
+            Steckers::Uhr { ref plugs, setting } => Steckers::uhr(plugs, setting, letter, false),
+        }
+    }
+
+    // Passes a letter through the Uhr. The a plugs meet one face of the disc and
+    // the b plugs the other, each with its keyboard pin at contact 4n and its
+    // entry wheel pin at 4n + 2 of its slot. The a plug of cable n takes slot n
+    // and its b plug the slot in UHR_B_SLOTS, so that at setting 00 the Uhr
+    // swaps each pair like the plugboard. Turning the dial turns the disc
+    // against both faces.
+    fn uhr(plugs: &[usize], setting: usize, letter: usize, forward: bool) -> usize {
+        let plug = match plugs.iter().position(|&p| p == letter) {
+            Some(plug) => plug,
+            None => return letter,
+        };
+        let (cable, a_plug) = (plug % 10, plug < 10);
+        let slot = if a_plug { cable } else { UHR_B_SLOTS[cable] };
+        let pin = 4 * slot + if forward { 0 } else { 2 };
+
+        let contact = (pin + setting) % 40;
+        let contact = if a_plug {
+            UHR[contact]
+        } else {
+            UHR.iter().position(|&c| c == contact).unwrap()
+        };
+        let slot = (contact + 40 - setting) % 40 / 4;
+        if a_plug {
+            plugs[10 + UHR_B_SLOTS.iter().position(|&s| s == slot).unwrap()]
+        } else {
+            plugs[slot]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enigma_i<'a>() -> EnigmaConfig<'a> {
+        EnigmaConfig {
+            entry: STRAIGHT,
+            rotors: [I, II, III],
+            reflector: Reflector::Fixed(UKW_B),
+            plugboard: Plugboard::Pairs(""),
+            stepping: Stepping::Pawls,
+        }
+    }
+
+    #[test]
+    fn reference_message() {
+        let e = Enigma::new(&enigma_i()).unwrap();
+        assert_eq!("BDZGO", e.encipher("AAAAA").unwrap());
+    }
+
+    #[test]
+    fn ring_settings() {
+        let mut c = enigma_i();
+        for rotor in c.rotors.iter_mut() {
+            rotor.ring = 'B';
+        }
+        let e = Enigma::new(&c).unwrap();
+        assert_eq!("EWTYX", e.encipher("AAAAA").unwrap());
+    }
+
+    #[test]
+    fn plugboard() {
+        let mut c = enigma_i();
+        c.plugboard = Plugboard::Pairs("AB CD");
+        let e = Enigma::new(&c).unwrap();
+        let ciphertext = e.encipher("Keep it secret").unwrap();
+        assert_eq!("Keep it secret", e.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn invalid_plugboard() {
+        let mut c = enigma_i();
+        c.plugboard = Plugboard::Pairs("AB AC");
+        assert!(Enigma::new(&c).is_err());
+    }
+
+    #[test]
+    fn invalid_entry() {
+        let mut c = enigma_i();
+        c.entry = "QWERTZ";
+        assert!(Enigma::new(&c).is_err());
+    }
+
+    #[test]
+    fn ukw_d() {
+        let mut c = enigma_i();
+        c.reflector = Reflector::Rewirable("AF BO CU DS EZ GX HW IK JY LQ MR NT PV");
+        let e = Enigma::new(&c).unwrap();
+        let ciphertext = e.encipher("Rewired in the field").unwrap();
+        assert_eq!("Rewired in the field", e.decipher(&ciphertext).unwrap());
+        assert_ne!("BDZGO", e.encipher("AAAAA").unwrap());
+    }
+
+    #[test]
+    fn ukw_d_wired_as_ukw_b() {
+        let mut c = enigma_i();
+        c.reflector = Reflector::Rewirable("AY BR CU DH EQ FS GL IP JX KN MO TZ VW");
+        let e = Enigma::new(&c).unwrap();
+        assert_eq!("BDZGO", e.encipher("AAAAA").unwrap());
+    }
+
+    #[test]
+    fn ukw_d_needs_every_letter() {
+        let mut c = enigma_i();
+        c.reflector = Reflector::Rewirable("AF BO CU DS EZ GX HW IK JY LQ MR NT");
+        assert!(Enigma::new(&c).is_err());
+    }
+
+    #[test]
+    fn uhr_at_00_is_plugboard() {
+        // The Barbarossa message of 1941, sent on an M3 with a plugboard of 10
+        // pairs, which the Uhr matches at its 00 setting.
+        let mut c = enigma_i();
+        c.rotors = [II, IV, V];
+        for (rotor, (ring, position)) in c.rotors.iter_mut().zip("BUL".chars().zip("BLA".chars())) {
+            rotor.ring = ring;
+            rotor.position = position;
+        }
+        c.plugboard = Plugboard::Uhr("AV BS CG DL FU HZ IN KM OW RX", 0);
+        let uhr = Enigma::new(&c).unwrap();
+        c.plugboard = Plugboard::Pairs("AV BS CG DL FU HZ IN KM OW RX");
+        let pairs = Enigma::new(&c).unwrap();
+
+        let ciphertext = "EDPUDNRGYSZRCXNUYTPOMRMBOFKTBZREZKMLXLVEFGUEYSIOZVEQMIKUBP";
+        let plaintext = "AUFKLXABTEILUNGXVONXKURTINOWAXKURTINOWAXNORDWESTLXSEBEZXSE";
+        assert_eq!(plaintext, uhr.decipher(ciphertext).unwrap());
+        assert_eq!(plaintext, pairs.decipher(ciphertext).unwrap());
+    }
+
+    #[test]
+    fn uhr_reciprocal_every_fourth_setting() {
+        for setting in 0..40 {
+            let steckers =
+                Steckers::new(&Plugboard::Uhr("AB CD EF GH IJ KL MN OP QR ST", setting)).unwrap();
+            let reciprocal = (0..26).all(|l| {
+                steckers.backward(steckers.forward(l)) == l
+                    && steckers.forward(steckers.forward(l)) == l
+            });
+            assert_eq!(setting % 4 == 0, reciprocal);
+        }
+    }
+
+    #[test]
+    fn uhr_keeps_machine_reciprocal() {
+        // The whole machine stays its own inverse at every Uhr setting.
+        for setting in 0..40 {
+            let mut c = enigma_i();
+            c.plugboard = Plugboard::Uhr("AB CD EF GH IJ KL MN OP QR ST", setting);
+            let e = Enigma::new(&c).unwrap();
+            let ciphertext = e.encipher("THE UHR WAS USED FROM 1944").unwrap();
+            assert_eq!(
+                "THE UHR WAS USED FROM 1944",
+                e.decipher(&ciphertext).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn uhr_is_not_reciprocal() {
+        let steckers = Steckers::new(&Plugboard::Uhr("AB CD EF GH IJ KL MN OP QR ST", 1)).unwrap();
+        assert!((0..26).any(|l| steckers.forward(steckers.forward(l)) != l));
+    }
+
+    #[test]
+    fn uhr_settings_differ() {
+        let ciphertexts = [0, 1, 2]
+            .iter()
+            .map(|&setting| {
+                let mut c = enigma_i();
+                c.plugboard = Plugboard::Uhr("AB CD EF GH IJ KL MN OP QR ST", setting);
+                Enigma::new(&c).unwrap().encipher("AAAAAAAAAA").unwrap()
+            })
+            .collect::<Vec<String>>();
+        assert_ne!(ciphertexts[0], ciphertexts[1]);
+        assert_ne!(ciphertexts[1], ciphertexts[2]);
+    }
+
+    #[test]
+    fn uhr_needs_ten_cables() {
+        let mut c = enigma_i();
+        c.plugboard = Plugboard::Uhr("AB CD", 0);
+        assert!(Enigma::new(&c).is_err());
+    }
+
+    #[test]
+    fn swiss_k() {
+        let mut reflector = K_UKW;
+        reflector.position = 'F';
+        let e = Enigma::new(&EnigmaConfig {
+            entry: QWERTZ,
+            rotors: [K_III, K_I, K_II],
+            reflector: Reflector::Settable(reflector),
+            plugboard: Plugboard::Pairs(""),
+            stepping: Stepping::Pawls,
+        })
+        .unwrap();
+        let ciphertext = e.encipher("Bern calling").unwrap();
+        assert_eq!("Bern calling", e.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn settable_reflector_position() {
+        let config = |position| {
+            let mut reflector = RAILWAY_UKW;
+            reflector.position = position;
+            EnigmaConfig {
+                entry: QWERTZ,
+                rotors: [RAILWAY_I, RAILWAY_II, RAILWAY_III],
+                reflector: Reflector::Settable(reflector),
+                plugboard: Plugboard::Pairs(""),
+                stepping: Stepping::Pawls,
+            }
+        };
+        let a = Enigma::new(&config('A')).unwrap();
+        let b = Enigma::new(&config('Q')).unwrap();
+        assert_ne!(
+            a.encipher("RAILWAY").unwrap(),
+            b.encipher("RAILWAY").unwrap()
+        );
+        let ciphertext = b.encipher("Railway").unwrap();
+        assert_eq!("Railway", b.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn enigma_g_turns_the_reflector() {
+        let config = |reflector| EnigmaConfig {
+            entry: QWERTZ,
+            rotors: [G_I, G_II, G_III],
+            reflector,
+            plugboard: Plugboard::Pairs(""),
+            stepping: Stepping::Gears,
+        };
+        let moving = Enigma::new(&config(Reflector::Settable(G_UKW))).unwrap();
+        let fixed = Enigma::new(&config(Reflector::Fixed(G_UKW.wiring))).unwrap();
+
+        // Every rotor starts on a notch at A, so the first letter carries through
+        // to the reflector.
+        let ciphertext = moving.encipher("Abwehr").unwrap();
+        assert_ne!(fixed.encipher("Abwehr").unwrap(), ciphertext);
+        assert_eq!("Abwehr", moving.decipher(&ciphertext).unwrap());
+    }
+}
@@ -0,0 +1,406 @@
+use common::grid::Grid;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The direction a turning grille is rotated in between each quarter of the
+/// message.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rotation {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Turning Grille (Fleissner) Cipher
+///
+/// The struct is generated through the new() or random() functions.
+///
+pub struct TurningGrille {
+    size: usize,
+    holes: Vec<(usize, usize)>,
+    rotation: Rotation,
+}
+
+impl TurningGrille {
+    /// Initializes a turning grille cipher with the side length of the square
+    /// grille, the positions of its holes as (row, column) and the direction it is
+    /// turned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::grille::{Rotation, TurningGrille};
+    ///
+    /// let holes = [(0, 0), (0, 2), (1, 3), (2, 2)];
+    /// let t = TurningGrille::new(4, &holes, Rotation::Clockwise).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the size is not even and greater than zero, or if the
+    /// holes do not expose every cell exactly once over the four rotations.
+    ///
+    pub fn new(size: usize, holes: &[(usize, usize)], rotation: Rotation) -> Result<Self, String> {
+        if size == 0 || size % 2 == 1 {
+            return Err(String::from("The size must be even and greater than zero"));
+        }
+        if holes.len() != size * size / 4 {
+            return Err(String::from(
+                "The grille must have a quarter of its cells as holes",
+            ));
+        }
+
+        let mut exposed = vec![false; size * size];
+        for &hole in holes {
+            if hole.0 >= size || hole.1 >= size {
+                return Err(String::from("Hole is outside the grille"));
+            }
+            let mut cell = hole;
+            for _ in 0..4 {
+                if exposed[cell.0 * size + cell.1] {
+                    return Err(String::from("Every cell must be exposed exactly once"));
+                }
+                exposed[cell.0 * size + cell.1] = true;
+                cell = TurningGrille::rotate(size, cell, Rotation::Clockwise);
+            }
+        }
+
+        Ok(TurningGrille {
+            size,
+            holes: holes.to_vec(),
+            rotation,
+        })
+    }
+
+    /// Initializes a turning grille cipher with randomly placed holes, generated
+    /// from a seed so the same grille can be recreated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::grille::{Rotation, TurningGrille};
+    ///
+    /// let t = TurningGrille::random(6, Rotation::CounterClockwise, 1917).unwrap();
+    /// assert_eq!(9, t.holes().len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the size is not even and greater than zero.
+    ///
+    pub fn random(size: usize, rotation: Rotation, seed: u64) -> Result<Self, String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut holes = Vec::new();
+        for row in 0..size / 2 {
+            for col in 0..size / 2 {
+                let mut hole = (row, col);
+                for _ in 0..rng.gen_range(0..4) {
+                    hole = TurningGrille::rotate(size, hole, Rotation::Clockwise);
+                }
+                holes.push(hole);
+            }
+        }
+        holes.sort_unstable();
+        TurningGrille::new(size, &holes, rotation)
+    }
+
+    /// Returns the positions of the holes as (row, column).
+    ///
+    pub fn holes(&self) -> &[(usize, usize)] {
+        &self.holes
+    }
+
+    /// Enciphers a message with a turning grille cipher.
+    ///
+    /// Messages longer than the grille are enciphered one grille at a time. The
+    /// last grille is left incomplete rather than padded, and its empty cells are
+    /// skipped when it is read out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::grille::{Rotation, TurningGrille};
+    ///
+    /// let holes = [(0, 0), (0, 2), (1, 3), (2, 2)];
+    /// let t = TurningGrille::new(4, &holes, Rotation::Clockwise).unwrap();
+    /// assert_eq!("AMBENIOCJFDGPKHL", t.encipher("ABCDEFGHIJKLMNOP").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        Ok(self
+            .blocks(plaintext)
+            .iter()
+            .map(|block| Grid::transpose(block, &self.order(block.len())))
+            .collect())
+    }
+
+    /// Deciphers a message with a turning grille cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::grille::{Rotation, TurningGrille};
+    ///
+    /// let holes = [(0, 0), (0, 2), (1, 3), (2, 2)];
+    /// let t = TurningGrille::new(4, &holes, Rotation::Clockwise).unwrap();
+    /// assert_eq!("ABCDEFGHIJKLMNOP", t.decipher("AMBENIOCJFDGPKHL").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        Ok(self
+            .blocks(ciphertext)
+            .iter()
+            .map(|block| Grid::restore(block, &self.order(block.len())))
+            .collect())
+    }
+
+    // Calculates the order the characters of one grille are read out in, for a
+    // grille holding the given number of characters.
+    fn order(&self, len: usize) -> Vec<usize> {
+        let mut holes = self.holes.clone();
+        let mut positions = Vec::new();
+        for _ in 0..4 {
+            holes.sort_unstable();
+            positions.extend_from_slice(&holes);
+            for hole in holes.iter_mut() {
+                *hole = TurningGrille::rotate(self.size, *hole, self.rotation);
+            }
+        }
+
+        let mut grid = Grid::new(self.size, self.size * self.size);
+        grid.write(&positions);
+        let rows = (0..self.size * self.size)
+            .map(|p| (p / self.size, p % self.size))
+            .collect::<Vec<(usize, usize)>>();
+        grid.read(&rows).into_iter().filter(|&p| p < len).collect()
+    }
+
+    // Splits the text into grilles, the last of which may be incomplete.
+    fn blocks(&self, text: &str) -> Vec<Vec<char>> {
+        text.chars()
+            .collect::<Vec<char>>()
+            .chunks(self.size * self.size)
+            .map(|block| block.to_vec())
+            .collect()
+    }
+
+    // Turns a position a quarter of the way around the grille.
+    fn rotate(size: usize, (row, col): (usize, usize), rotation: Rotation) -> (usize, usize) {
+        match rotation {
+            Rotation::Clockwise => (col, size - 1 - row),
+            Rotation::CounterClockwise => (size - 1 - col, row),
+        }
+    }
+}
+
+/// Cardan Grille
+///
+/// The struct is generated through the new() function.
+///
+pub struct CardanGrille {
+    holes: Vec<usize>,
+}
+
+impl CardanGrille {
+    /// Initializes a cardan grille with the width of the page and the positions of
+    /// its holes as (row, column).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::grille::CardanGrille;
+    ///
+    /// let c = CardanGrille::new(5, &[(0, 1), (1, 3), (2, 0)]).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the grille has no holes, the same hole twice or a hole
+    /// outside the width.
+    ///
+    pub fn new(width: usize, holes: &[(usize, usize)]) -> Result<Self, String> {
+        if holes.is_empty() {
+            return Err(String::from("The grille must have at least one hole"));
+        }
+        if holes.iter().any(|&(_, col)| col >= width) {
+            return Err(String::from("Hole is outside the grille"));
+        }
+
+        let mut positions = holes
+            .iter()
+            .map(|&(row, col)| row * width + col)
+            .collect::<Vec<usize>>();
+        positions.sort_unstable();
+        positions.dedup();
+        if positions.len() != holes.len() {
+            return Err(String::from("Holes must be unique"));
+        }
+
+        Ok(CardanGrille { holes: positions })
+    }
+
+    /// Hides a message in cover text by writing it through the holes, reading the
+    /// holes by rows. The cover text is laid out by rows under the grille and any
+    /// holes after the end of the message keep the cover text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::grille::CardanGrille;
+    ///
+    /// let c = CardanGrille::new(5, &[(0, 1), (1, 3), (2, 0)]).unwrap();
+    /// assert_eq!(
+    ///     "aScdefghOjSlmno",
+    ///     c.encipher("SOS", "abcdefghijklmno").unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the message is longer than the number of holes or the
+    /// cover text does not reach the last hole.
+    ///
+    pub fn encipher(&self, plaintext: &str, cover: &str) -> Result<String, String> {
+        if plaintext.chars().count() > self.holes.len() {
+            return Err(String::from("The message is too long for the grille"));
+        }
+        let mut text = cover.chars().collect::<Vec<char>>();
+        if text.len() <= *self.holes.last().unwrap() {
+            return Err(String::from("The cover text is too short for the grille"));
+        }
+
+        for (&p, c) in self.holes.iter().zip(plaintext.chars()) {
+            text[p] = c;
+        }
+        Ok(text.iter().collect())
+    }
+
+    /// Reads a hidden message through the holes of the grille.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::grille::CardanGrille;
+    ///
+    /// let c = CardanGrille::new(5, &[(0, 1), (1, 3), (2, 0)]).unwrap();
+    /// assert_eq!("SOS", c.decipher("aScdefghOjSlmno").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the text does not reach the last hole.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let text = ciphertext.chars().collect::<Vec<char>>();
+        if text.len() <= *self.holes.last().unwrap() {
+            return Err(String::from("The text is too short for the grille"));
+        }
+        Ok(self.holes.iter().map(|&p| text[p]).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CardanGrille, Rotation, TurningGrille};
+
+    const HOLES: [(usize, usize); 4] = [(0, 0), (0, 2), (1, 3), (2, 2)];
+
+    #[test]
+    fn valid_grille() {
+        assert!(TurningGrille::new(4, &HOLES, Rotation::Clockwise).is_ok());
+    }
+
+    #[test]
+    fn odd_size() {
+        assert!(TurningGrille::new(3, &[(0, 0), (0, 1)], Rotation::Clockwise).is_err());
+    }
+
+    #[test]
+    fn cell_exposed_twice() {
+        let holes = [(0, 0), (0, 3), (1, 3), (2, 2)];
+        assert!(TurningGrille::new(4, &holes, Rotation::Clockwise).is_err());
+    }
+
+    #[test]
+    fn too_few_holes() {
+        assert!(TurningGrille::new(4, &HOLES[..3], Rotation::Clockwise).is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let t = TurningGrille::new(4, &HOLES, Rotation::Clockwise).unwrap();
+        assert_eq!("AMBENIOCJFDGPKHL", t.encipher("ABCDEFGHIJKLMNOP").unwrap());
+    }
+
+    #[test]
+    fn decipher() {
+        let t = TurningGrille::new(4, &HOLES, Rotation::Clockwise).unwrap();
+        assert_eq!("ABCDEFGHIJKLMNOP", t.decipher("AMBENIOCJFDGPKHL").unwrap());
+    }
+
+    #[test]
+    fn counter_clockwise() {
+        let t = TurningGrille::new(4, &HOLES, Rotation::CounterClockwise).unwrap();
+        assert_eq!("AEBMFIGCJNDOHKPL", t.encipher("ABCDEFGHIJKLMNOP").unwrap());
+    }
+
+    #[test]
+    fn multiple_grilles() {
+        let t = TurningGrille::new(4, &HOLES, Rotation::Clockwise).unwrap();
+        let plaintext = "Turning grilles hide text";
+        let ciphertext = t.encipher(plaintext).unwrap();
+        assert_eq!(25, ciphertext.chars().count());
+        assert_eq!(plaintext, t.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn keeps_trailing_spaces() {
+        let t = TurningGrille::new(4, &HOLES, Rotation::Clockwise).unwrap();
+        let ciphertext = t.encipher("Hide me  ").unwrap();
+        assert_eq!("Hide me  ", t.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn random_grille() {
+        let t = TurningGrille::random(8, Rotation::Clockwise, 42).unwrap();
+        assert_eq!(16, t.holes().len());
+        let plaintext = "The random grille is valid for any seed we choose";
+        assert_eq!(
+            plaintext,
+            t.decipher(&t.encipher(plaintext).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn random_grille_is_seeded() {
+        let a = TurningGrille::random(10, Rotation::Clockwise, 7).unwrap();
+        let b = TurningGrille::random(10, Rotation::Clockwise, 7).unwrap();
+        assert_eq!(a.holes(), b.holes());
+    }
+
+    #[test]
+    fn cardan_duplicate_hole() {
+        assert!(CardanGrille::new(5, &[(0, 1), (0, 1)]).is_err());
+    }
+
+    #[test]
+    fn cardan_encipher() {
+        let c = CardanGrille::new(5, &[(0, 1), (1, 3), (2, 0)]).unwrap();
+        assert_eq!(
+            "aScdefghOjSlmno",
+            c.encipher("SOS", "abcdefghijklmno").unwrap()
+        );
+    }
+
+    #[test]
+    fn cardan_decipher() {
+        let c = CardanGrille::new(5, &[(0, 1), (1, 3), (2, 0)]).unwrap();
+        assert_eq!("SOS", c.decipher("aScdefghOjSlmno").unwrap());
+    }
+
+    #[test]
+    fn cardan_short_cover() {
+        let c = CardanGrille::new(5, &[(0, 1), (1, 3), (2, 0)]).unwrap();
+        assert!(c.encipher("SOS", "abcdefghij").is_err());
+    }
+}
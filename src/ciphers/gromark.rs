@@ -0,0 +1,237 @@
+use common::{column_order, ALPHABET};
+use keygen::columnar_mixed;
+
+/// Gromark Cipher
+///
+/// The struct is generated through the new() or periodic() functions.
+///
+pub struct Gromark {
+    alphabet: Vec<char>,
+    primer: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl Gromark {
+    /// Initializes a Gromark cipher with a keyword and a five digit primer.
+    ///
+    /// The cipher alphabet is the keyword mixed alphabet written under the
+    /// keyword and read off by columns in alphabetical order. The primer is
+    /// extended into a running key by adding each pair of neighbouring digits
+    /// without carrying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::gromark::Gromark;
+    ///
+    /// let g = Gromark::new("enigma", "23452").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the keyword is empty or not alphabetic, or the primer
+    /// is not five digits.
+    ///
+    pub fn new(keyword: &str, primer: &str) -> Result<Self, String> {
+        if primer.len() != 5 || !primer.chars().all(|c| c.is_ascii_digit()) {
+            return Err(String::from("Primer must be five digits"));
+        }
+        Ok(Gromark {
+            alphabet: columnar_mixed(keyword)?.chars().collect(),
+            primer: primer.bytes().map(|b| b - b'0').collect(),
+            offsets: vec![0],
+        })
+    }
+
+    /// Initializes a periodic Gromark cipher with a keyword.
+    ///
+    /// The cipher alphabet is built as for the Gromark cipher, and the primer is
+    /// the alphabetical order of the keyword's letters, numbered from 1 with 10
+    /// written as 0. The period is the length of the keyword, and each period
+    /// slides the cipher alphabet to start at the next letter of the keyword.
+    ///
+    /// This follows descriptions of the periodic Gromark, but has not been checked
+    /// against a published example.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::gromark::Gromark;
+    ///
+    /// let g = Gromark::periodic("enigma").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the keyword is shorter than two letters, not
+    /// alphabetic or repeats a letter, as the chain addition needs a primer of
+    /// at least two digits.
+    ///
+    pub fn periodic(keyword: &str) -> Result<Self, String> {
+        let keyword = keyword.to_ascii_uppercase();
+        if keyword.chars().count() < 2 {
+            return Err(String::from("Keyword must have at least two letters"));
+        }
+        if keyword
+            .char_indices()
+            .any(|(i, c)| keyword[..i].contains(c))
+        {
+            return Err(String::from("Keyword must not repeat a letter"));
+        }
+        let alphabet = columnar_mixed(&keyword)?.chars().collect::<Vec<char>>();
+
+        let mut primer = vec![0; keyword.len()];
+        for (rank, i) in column_order(&keyword).into_iter().enumerate() {
+            primer[i] = ((rank + 1) % 10) as u8;
+        }
+        let offsets = keyword
+            .chars()
+            .map(|c| alphabet.iter().position(|&a| a == c).unwrap())
+            .collect();
+
+        Ok(Gromark {
+            alphabet,
+            primer,
+            offsets,
+        })
+    }
+
+    /// Enciphers a message with a Gromark cipher.
+    ///
+    /// Each letter is moved on by the next digit of the running key and then
+    /// replaced by the letter of the cipher alphabet in its place. The case of
+    /// letters is kept and other characters are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::gromark::Gromark;
+    ///
+    /// let g = Gromark::new("enigma", "23452").unwrap();
+    /// assert_eq!(
+    ///     "NFYCKBTIJCNWZYCACJNAYNLQPWWSTWPJQFL",
+    ///     g.encipher("THEREAREUPTOTENSUBSTITUTESPERLETTER").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        Ok(self.crypt(plaintext, false))
+    }
+
+    /// Deciphers a message with a Gromark cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::gromark::Gromark;
+    ///
+    /// let g = Gromark::new("enigma", "23452").unwrap();
+    /// assert_eq!(
+    ///     "THEREAREUPTOTENSUBSTITUTESPERLETTER",
+    ///     g.decipher("NFYCKBTIJCNWZYCACJNAYNLQPWWSTWPJQFL").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        Ok(self.crypt(ciphertext, true))
+    }
+
+    // Runs the letters of the text through the cipher alphabets, advancing the
+    // running key by chain addition.
+    fn crypt(&self, text: &str, reverse: bool) -> String {
+        let mut key = self.primer.clone();
+        let period = self.primer.len();
+        let mut i = 0;
+
+        text.chars()
+            .map(|c| {
+                if !c.is_ascii_alphabetic() {
+                    return c;
+                }
+                if i == key.len() {
+                    key.push((key[i - period] + key[i - period + 1]) % 10);
+                }
+                let k = key[i] as usize;
+                let offset = self.offsets[(i / period) % self.offsets.len()];
+                i += 1;
+
+                let upper = c.to_ascii_uppercase();
+                let output = if reverse {
+                    let p = self.alphabet.iter().position(|&a| a == upper).unwrap();
+                    ALPHABET.as_bytes()[(p + 52 - offset - k) % 26] as char
+                } else {
+                    let p = ALPHABET.chars().position(|a| a == upper).unwrap();
+                    self.alphabet[(p + k + offset) % 26]
+                };
+                if c.is_ascii_lowercase() {
+                    output.to_ascii_lowercase()
+                } else {
+                    output
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gromark;
+
+    #[test]
+    fn invalid_primer() {
+        assert!(Gromark::new("enigma", "2345").is_err());
+        assert!(Gromark::new("enigma", "2345a").is_err());
+    }
+
+    #[test]
+    fn invalid_keyword() {
+        assert!(Gromark::new("", "23452").is_err());
+        assert!(Gromark::periodic("en1gma").is_err());
+        assert!(Gromark::periodic("letter").is_err());
+        assert!(Gromark::periodic("énigma").is_err());
+    }
+
+    #[test]
+    fn periodic_one_letter_keyword() {
+        assert!(Gromark::periodic("a").is_err());
+        assert!(Gromark::periodic("").is_err());
+
+        let g = Gromark::periodic("ab").unwrap();
+        let ciphertext = g.encipher("abc").unwrap();
+        assert_eq!("abc", g.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn encipher() {
+        let g = Gromark::new("enigma", "23452").unwrap();
+        assert_eq!(
+            "NFYCKBTIJCNWZYCACJNAYNLQPWWSTWPJQFL",
+            g.encipher("THEREAREUPTOTENSUBSTITUTESPERLETTER").unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_case_and_punctuation() {
+        let g = Gromark::new("enigma", "23452").unwrap();
+        assert_eq!("Nfy ck-bt!", g.encipher("The re-ar!").unwrap());
+        assert_eq!("The re-ar!", g.decipher("Nfy ck-bt!").unwrap());
+    }
+
+    #[test]
+    fn periodic_primer() {
+        // ENIGMA numbers its letters 2 6 4 3 5 1, and the first period uses the
+        // cipher alphabet slid to start at E, so each A is enciphered by the
+        // letter that many places after E.
+        let g = Gromark::periodic("enigma").unwrap();
+        assert_eq!("KFYSG", g.encipher("AAAAA").unwrap());
+    }
+
+    #[test]
+    fn periodic_round_trip() {
+        let g = Gromark::periodic("enigma").unwrap();
+        let plaintext = "Each period slides the alphabet to the next keyword letter";
+        let ciphertext = g.encipher(plaintext).unwrap();
+        assert_ne!(plaintext, ciphertext);
+        assert_eq!(plaintext, g.decipher(&ciphertext).unwrap());
+    }
+}
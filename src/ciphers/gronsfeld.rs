@@ -0,0 +1,112 @@
+use ciphers::vigenere::Vigenere;
+
+/// Gronsfeld Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Gronsfeld {
+    key: Vec<u8>,
+}
+
+impl Gronsfeld {
+    /// Initializes a gronsfeld cipher with a supplied numeric key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::gronsfeld::Gronsfeld;
+    ///
+    /// let g = Gronsfeld::new("31415").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key is empty or contains anything other than digits.
+    ///
+    pub fn new(key: &str) -> Result<Self, String> {
+        if key.is_empty() {
+            return Err(String::from("Key must not be empty"));
+        }
+
+        let mut digits = Vec::new();
+        for c in key.chars() {
+            match c.to_digit(10) {
+                Some(d) => digits.push(d as u8),
+                None => return Err(String::from("Key must be numeric")),
+            }
+        }
+        Ok(Gronsfeld { key: digits })
+    }
+
+    /// Enciphers a message with a gronsfeld cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::gronsfeld::Gronsfeld;
+    ///
+    /// let g = Gronsfeld::new("31415").unwrap();
+    /// assert_eq!("Duxbhn bx efzo", g.encipher("Attack at dawn").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, &'static str> {
+        Vigenere::transpose(&self.key, plaintext)
+    }
+
+    /// Deciphers a message with a gronsfeld cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::gronsfeld::Gronsfeld;
+    ///
+    /// let g = Gronsfeld::new("31415").unwrap();
+    /// assert_eq!("Attack at dawn", g.decipher("Duxbhn bx efzo").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, &'static str> {
+        let filter = self.key.iter().map(|n| (26 - n) % 26).collect::<Vec<u8>>();
+        Vigenere::transpose(&filter, ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gronsfeld;
+
+    #[test]
+    fn valid_key() {
+        assert!(Gronsfeld::new("31415").is_ok());
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(Gronsfeld::new("3141S").is_err());
+    }
+
+    #[test]
+    fn empty_key() {
+        assert!(Gronsfeld::new("").is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let g = Gronsfeld::new("31415").unwrap();
+        assert_eq!("Duxbhn bx efzo", g.encipher("Attack at dawn").unwrap());
+    }
+
+    #[test]
+    fn decipher() {
+        let g = Gronsfeld::new("31415").unwrap();
+        assert_eq!("Attack at dawn", g.decipher("Duxbhn bx efzo").unwrap());
+    }
+
+    #[test]
+    fn with_unicode() {
+        let g = Gronsfeld::new("2718").unwrap();
+        assert_eq!(
+            "K 🖤 jsgrapothqpa",
+            g.encipher("I 🖤 cryptography").unwrap()
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use ciphers::substitution::Substitution;
+use common::ALPHABET;
+
+/// Kama-Sutra Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct KamaSutra {
+    pairs: String,
+    substitution: Substitution,
+}
+
+impl KamaSutra {
+    /// Initializes a Kama-Sutra cipher with thirteen pairs of letters, written
+    /// like "AB CD", in which each letter is swapped with its partner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::kamasutra::KamaSutra;
+    ///
+    /// let k = KamaSutra::new("AN BO CP DQ ER FS GT HU IV JW KX LY MZ").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if there are not thirteen pairs of two letters, or a
+    /// letter is used more than once, as every letter must have one partner.
+    ///
+    pub fn new(pairs: &str) -> Result<Self, String> {
+        let pairs = pairs.to_ascii_uppercase();
+        let letters = pairs.split_whitespace().collect::<Vec<&str>>();
+        if letters.len() != 13 {
+            return Err(String::from("There must be 13 pairs"));
+        }
+
+        let mut key = ALPHABET.chars().collect::<Vec<char>>();
+        let mut used = String::new();
+        for pair in letters {
+            let pair = pair.chars().collect::<Vec<char>>();
+            if pair.len() != 2 || !pair.iter().all(|c| c.is_ascii_alphabetic()) {
+                return Err(String::from("Pairs must be two letters"));
+            }
+            if pair.iter().any(|&c| used.contains(c)) || pair[0] == pair[1] {
+                return Err(String::from("Each letter must be paired once"));
+            }
+            used.extend(pair.iter());
+            key[(pair[0] as u8 - 65) as usize] = pair[1];
+            key[(pair[1] as u8 - 65) as usize] = pair[0];
+        }
+
+        Ok(KamaSutra {
+            pairs,
+            substitution: Substitution::new(&key.iter().collect::<String>())?,
+        })
+    }
+
+    /// Initializes a Kama-Sutra cipher with the letters paired at random,
+    /// generated from a seed so the pairs can be recreated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::kamasutra::KamaSutra;
+    ///
+    /// let k = KamaSutra::random(7);
+    /// assert_eq!(k.pairs(), KamaSutra::random(7).pairs());
+    /// ```
+    ///
+    pub fn random(seed: u64) -> Self {
+        let mut letters = ALPHABET.chars().collect::<Vec<char>>();
+        letters.shuffle(&mut StdRng::seed_from_u64(seed));
+        let pairs = letters
+            .chunks(2)
+            .map(|pair| pair.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join(" ");
+        KamaSutra::new(&pairs).unwrap()
+    }
+
+    /// The pairs of letters that are swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::kamasutra::KamaSutra;
+    ///
+    /// let k = KamaSutra::new("an bo cp dq er fs gt hu iv jw kx ly mz").unwrap();
+    /// assert_eq!("AN BO CP DQ ER FS GT HU IV JW KX LY MZ", k.pairs());
+    /// ```
+    ///
+    pub fn pairs(&self) -> &str {
+        &self.pairs
+    }
+
+    /// Enciphers a message with a Kama-Sutra cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::kamasutra::KamaSutra;
+    ///
+    /// let k = KamaSutra::new("AN BO CP DQ ER FS GT HU IV JW KX LY MZ").unwrap();
+    /// assert_eq!("Zrrg zr ng zvqavtug", k.encipher("Meet me at midnight").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        self.substitution.encipher(plaintext).map_err(String::from)
+    }
+
+    /// Deciphers a message with a Kama-Sutra cipher, which is its own inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::kamasutra::KamaSutra;
+    ///
+    /// let k = KamaSutra::new("AN BO CP DQ ER FS GT HU IV JW KX LY MZ").unwrap();
+    /// assert_eq!("Meet me at midnight", k.decipher("Zrrg zr ng zvqavtug").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        self.substitution.decipher(ciphertext).map_err(String::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KamaSutra;
+
+    #[test]
+    fn too_few_pairs() {
+        assert!(KamaSutra::new("AN BO CP DQ ER FS GT HU IV JW KX LY").is_err());
+    }
+
+    #[test]
+    fn repeated_letter() {
+        assert!(KamaSutra::new("AN BO CP DQ ER FS GT HU IV JW KX LY MA").is_err());
+    }
+
+    #[test]
+    fn letter_paired_with_itself() {
+        assert!(KamaSutra::new("AN BO CP DQ ER FS GT HU IV JW KX LY MM").is_err());
+    }
+
+    #[test]
+    fn invalid_pair() {
+        assert!(KamaSutra::new("AN BO CP DQ ER FS GT HU IV JW KX LY M1").is_err());
+        assert!(KamaSutra::new("ANB O CP DQ ER FS GT HU IV JW KX LY MZ").is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let k = KamaSutra::new("AZ BY CX DW EV FU GT HS IR JQ KP LO MN").unwrap();
+        assert_eq!("Svool, Dliow!", k.encipher("Hello, World!").unwrap());
+    }
+
+    #[test]
+    fn is_an_involution() {
+        let k = KamaSutra::random(42);
+        let text = "The Kama Sutra recommends secret writing 🖤";
+        let ciphertext = k.encipher(text).unwrap();
+        assert_eq!(ciphertext, k.decipher(text).unwrap());
+        assert_eq!(text, k.encipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn random_pairs_every_letter() {
+        let k = KamaSutra::random(3);
+        assert!(KamaSutra::new(k.pairs()).is_ok());
+        assert_ne!(KamaSutra::random(4).pairs(), k.pairs());
+    }
+}
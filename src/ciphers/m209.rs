@@ -0,0 +1,352 @@
+/// The letters on each of the six key wheels.
+pub const WHEELS: [&str; 6] = [
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    "ABCDEFGHIJKLMNOPQRSTUVXYZ",
+    "ABCDEFGHIJKLMNOPQRSTUVX",
+    "ABCDEFGHIJKLMNOPQRSTU",
+    "ABCDEFGHIJKLMNOPQRS",
+    "ABCDEFGHIJKLMNOPQ",
+];
+
+// How far past the letter showing in the window each wheel's pins are sensed.
+const SENSED: [usize; 6] = [15, 14, 13, 12, 11, 10];
+
+/// M-209 Cipher
+///
+/// The pin sensing offsets and the reversed print wheel follow descriptions of
+/// the machine, but the cipher has not been checked against a published key
+/// list and message, so its output should not be relied on to match traffic
+/// from a real M-209.
+///
+/// The struct is generated through the new() function.
+///
+pub struct M209 {
+    pins: Vec<Vec<bool>>,
+    lugs: Vec<(usize, usize)>,
+    indicator: Vec<usize>,
+}
+
+impl M209 {
+    /// Initializes an M-209 with the drum lugs and key wheel pins of a key list,
+    /// and the six letter indicator the key wheels are set to.
+    ///
+    /// The lugs are written as a bar for each pair of wheel numbers, where 0 is a
+    /// lug in a neutral position, and `*n` repeats a bar `n` times. There must be
+    /// 27 bars. The pins of each wheel are written as the letters of its active
+    /// pins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::m209::M209;
+    ///
+    /// let m = M209::new(
+    ///     "1-0 2-0*4 0-3*4 0-4*3 0-5*3 0-6*9 2-5 2-6 3-4",
+    ///     [
+    ///         "BFJKLOSTUWXZ",
+    ///         "ABDJKLMORTUV",
+    ///         "EHJKNPQRSX",
+    ///         "ABCHIJLMPQR",
+    ///         "BCDGJLNOPQS",
+    ///         "AEFHIJP",
+    ///     ],
+    ///     "AAAAAA",
+    /// ).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the lugs are not 27 bars of two lugs on different
+    /// wheels, a pin or indicator letter is not on its wheel, or a pin is repeated.
+    ///
+    pub fn new(lugs: &str, pins: [&str; 6], indicator: &str) -> Result<Self, String> {
+        let lugs = M209::parse_lugs(lugs)?;
+
+        let mut wheels = Vec::new();
+        for (letters, active) in WHEELS.iter().zip(pins.iter()) {
+            let active = active.to_ascii_uppercase();
+            if !active.chars().all(|c| letters.contains(c)) {
+                return Err(String::from("Pins must be letters on their wheel"));
+            }
+            if active
+                .chars()
+                .enumerate()
+                .any(|(i, c)| active[..i].contains(c))
+            {
+                return Err(String::from("Pins must not be repeated"));
+            }
+            wheels.push(letters.chars().map(|c| active.contains(c)).collect());
+        }
+
+        let indicator = indicator.to_ascii_uppercase();
+        if indicator.chars().count() != 6 {
+            return Err(String::from("Indicator must be 6 letters"));
+        }
+        let mut positions = Vec::new();
+        for (letters, c) in WHEELS.iter().zip(indicator.chars()) {
+            match letters.chars().position(|l| l == c) {
+                Some(p) => positions.push(p),
+                None => return Err(String::from("Indicator letters must be on their wheel")),
+            }
+        }
+
+        Ok(M209 {
+            pins: wheels,
+            lugs,
+            indicator: positions,
+        })
+    }
+
+    /// Enciphers a message with an M-209.
+    ///
+    /// Spaces are enciphered as the letter Z, other characters that are not
+    /// letters are removed, and the ciphertext is printed in groups of five.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::m209::M209;
+    ///
+    /// let m = M209::new(
+    ///     "1-0 2-0*4 0-3*4 0-4*3 0-5*3 0-6*9 2-5 2-6 3-4",
+    ///     [
+    ///         "BFJKLOSTUWXZ",
+    ///         "ABDJKLMORTUV",
+    ///         "EHJKNPQRSX",
+    ///         "ABCHIJLMPQR",
+    ///         "BCDGJLNOPQS",
+    ///         "AEFHIJP",
+    ///     ],
+    ///     "AAAAAA",
+    /// ).unwrap();
+    /// assert_eq!("QGPRK QGSHM FVTM", m.encipher("Attack at dawn").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let letters = plaintext
+            .chars()
+            .filter_map(|c| match c {
+                ' ' => Some('Z'),
+                c if c.is_ascii_alphabetic() => Some(c.to_ascii_uppercase()),
+                _ => None,
+            })
+            .collect::<Vec<char>>();
+
+        let ciphertext = self.crypt(&letters);
+        Ok(ciphertext
+            .chunks(5)
+            .map(|group| group.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join(" "))
+    }
+
+    /// Deciphers a message with an M-209.
+    ///
+    /// Spaces in the ciphertext are ignored and the letter Z is printed as a space,
+    /// as the machine does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::m209::M209;
+    ///
+    /// let m = M209::new(
+    ///     "1-0 2-0*4 0-3*4 0-4*3 0-5*3 0-6*9 2-5 2-6 3-4",
+    ///     [
+    ///         "BFJKLOSTUWXZ",
+    ///         "ABDJKLMORTUV",
+    ///         "EHJKNPQRSX",
+    ///         "ABCHIJLMPQR",
+    ///         "BCDGJLNOPQS",
+    ///         "AEFHIJP",
+    ///     ],
+    ///     "AAAAAA",
+    /// ).unwrap();
+    /// assert_eq!("ATTACK AT DAWN", m.decipher("QGPRK QGSHM FVTM").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the ciphertext has characters other than letters and
+    /// spaces.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let mut letters = Vec::new();
+        for c in ciphertext.chars().filter(|&c| c != ' ') {
+            if !c.is_ascii_alphabetic() {
+                return Err(String::from("Ciphertext must be letters"));
+            }
+            letters.push(c.to_ascii_uppercase());
+        }
+
+        Ok(self
+            .crypt(&letters)
+            .iter()
+            .map(|&c| if c == 'Z' { ' ' } else { c })
+            .collect())
+    }
+
+    // Passes each letter through the machine, which is its own inverse. The drum
+    // sets the number of bars kicked out by the active pins, then the print wheel
+    // gives the letter that many places on from the reversed alphabet.
+    fn crypt(&self, letters: &[char]) -> Vec<char> {
+        let mut positions = self.indicator.clone();
+        letters
+            .iter()
+            .map(|&c| {
+                let active = (0..6)
+                    .map(|w| {
+                        let p = (positions[w] + SENSED[w]) % self.pins[w].len();
+                        self.pins[w][p]
+                    })
+                    .collect::<Vec<bool>>();
+                let kick = self
+                    .lugs
+                    .iter()
+                    .filter(|&&(a, b)| (a > 0 && active[a - 1]) || (b > 0 && active[b - 1]))
+                    .count();
+
+                for (w, p) in positions.iter_mut().enumerate() {
+                    *p = (*p + 1) % self.pins[w].len();
+                }
+
+                ((25 + kick + 26 - (c as usize - 65)) % 26 + 65) as u8 as char
+            })
+            .collect()
+    }
+
+    // Expands the lug settings of the drum into a pair of wheel numbers per bar.
+    fn parse_lugs(lugs: &str) -> Result<Vec<(usize, usize)>, String> {
+        let invalid = || String::from("Lugs must be written as bars like 1-6 or 0-4*3");
+
+        let mut bars = Vec::new();
+        for bar in lugs.split_whitespace() {
+            let (pair, count) = match bar.find('*') {
+                Some(i) => (&bar[..i], bar[i + 1..].parse().map_err(|_| invalid())?),
+                None => (bar, 1),
+            };
+            let wheels = pair
+                .split('-')
+                .map(|w| w.parse::<usize>().map_err(|_| invalid()))
+                .collect::<Result<Vec<usize>, String>>()?;
+            if wheels.len() != 2 || wheels.iter().any(|&w| w > 6) {
+                return Err(invalid());
+            }
+            if wheels[0] > 0 && wheels[0] == wheels[1] {
+                return Err(String::from(
+                    "The lugs of a bar must be on different wheels",
+                ));
+            }
+            for _ in 0..count {
+                bars.push((wheels[0], wheels[1]));
+            }
+        }
+
+        if bars.len() != 27 {
+            return Err(String::from("The drum must have 27 bars"));
+        }
+        Ok(bars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::M209;
+
+    const LUGS: &str = "1-0 2-0*4 0-3*4 0-4*3 0-5*3 0-6*9 2-5 2-6 3-4";
+    const PINS: [&str; 6] = [
+        "BFJKLOSTUWXZ",
+        "ABDJKLMORTUV",
+        "EHJKNPQRSX",
+        "ABCHIJLMPQR",
+        "BCDGJLNOPQS",
+        "AEFHIJP",
+    ];
+
+    #[test]
+    fn too_few_bars() {
+        assert!(M209::new("1-0 2-0*4", PINS, "AAAAAA").is_err());
+    }
+
+    #[test]
+    fn invalid_bar() {
+        assert!(M209::new("1-7 2-0*26", PINS, "AAAAAA").is_err());
+    }
+
+    #[test]
+    fn bar_on_one_wheel() {
+        assert!(M209::new("3-3 2-0*26", PINS, "AAAAAA").is_err());
+    }
+
+    #[test]
+    fn pin_not_on_wheel() {
+        let mut pins = PINS;
+        pins[5] = "AEFHIJZ";
+        assert!(M209::new(LUGS, pins, "AAAAAA").is_err());
+    }
+
+    #[test]
+    fn indicator_not_on_wheel() {
+        assert!(M209::new(LUGS, PINS, "AAAAAW").is_err());
+    }
+
+    #[test]
+    fn no_active_pins() {
+        let m = M209::new(LUGS, ["", "", "", "", "", ""], "AAAAAA").unwrap();
+        assert_eq!("ZYXWV U", m.encipher("ABCDEF").unwrap());
+    }
+
+    #[test]
+    fn sensed_pins() {
+        // With the wheels at A the pins sensed are P, O, N, M, L and K. Kicking
+        // all 27 bars prints A as A, and kicking none prints it as Z.
+        for (w, pin) in "PONMLK".chars().enumerate() {
+            let lugs = format!("{}-0*27", w + 1);
+            let mut pins = ["", "", "", "", "", ""];
+            let letter = pin.to_string();
+            pins[w] = &letter;
+            let m = M209::new(&lugs, pins, "AAAAAA").unwrap();
+            assert_eq!("AZ", m.encipher("AA").unwrap());
+        }
+    }
+
+    #[test]
+    fn overlapping_lugs_kick_once() {
+        let all = [
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            "ABCDEFGHIJKLMNOPQRSTUVXYZ",
+            "ABCDEFGHIJKLMNOPQRSTUVX",
+            "ABCDEFGHIJKLMNOPQRSTU",
+            "ABCDEFGHIJKLMNOPQRS",
+            "ABCDEFGHIJKLMNOPQ",
+        ];
+        let m = M209::new("1-2*27", all, "AAAAAA").unwrap();
+        assert_eq!("A", m.encipher("A").unwrap());
+    }
+
+    #[test]
+    fn kicks_from_active_pins() {
+        // The pins sensed are P, O, N, M, L and K: wheels 2 to 5 are active and
+        // kick 17 bars, then none are active, then wheels 3 and 5 kick 9 bars.
+        let m = M209::new(LUGS, PINS, "AAAAAA").unwrap();
+        assert_eq!("QZI", m.encipher("AAA").unwrap());
+    }
+
+    #[test]
+    fn letter_check() {
+        let m = M209::new(LUGS, PINS, "AAAAAA").unwrap();
+        let check = m.encipher(&"A".repeat(26)).unwrap();
+        assert_eq!("QZIRM AFSAL IVPZW JOLDI ONSNR O", check);
+        assert_eq!("A".repeat(26), m.decipher(&check).unwrap());
+    }
+
+    #[test]
+    fn decipher_spaces() {
+        let m = M209::new(LUGS, PINS, "GAMMAQ").unwrap();
+        let ciphertext = m.encipher("Attack at dawn, move north").unwrap();
+        assert_eq!(
+            "ATTACK AT DAWN MOVE NORTH",
+            m.decipher(&ciphertext).unwrap()
+        );
+    }
+}
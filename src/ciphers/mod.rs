@@ -1,7 +1,37 @@
+pub mod alberti;
+pub mod amsco;
 pub mod baconian;
+pub mod book;
 pub mod caesar;
+pub mod chaocipher;
+pub mod columnar;
+pub mod enigma;
+pub mod grille;
+pub mod gromark;
+pub mod gronsfeld;
+pub mod kamasutra;
+pub mod m209;
+pub mod myszkowski;
+pub mod nihilist;
+pub mod nomenclator;
+pub mod otp;
+pub mod pigpen;
+pub mod progressive;
+pub mod quagmire;
 pub mod railfence;
 pub mod rot13;
+pub mod rotor;
+pub mod route;
 pub mod scytale;
+pub mod sigaba;
+pub mod solitaire;
 pub mod substitution;
+pub mod tap;
+pub mod trifid;
+pub mod trithemius;
+pub mod twosquare;
+pub mod typex;
+pub mod vic;
 pub mod vigenere;
+pub mod wheel;
+pub mod xor;
@@ -0,0 +1,135 @@
+use ciphers::columnar::validate_key;
+use common::grid::Grid;
+
+/// Myszkowski Transposition Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Myszkowski {
+    groups: Vec<Vec<usize>>,
+}
+
+impl Myszkowski {
+    /// Initializes a myszkowski transposition cipher with a supplied key.
+    ///
+    /// Columns are read in the alphabetical order of the key, except that columns
+    /// under the same key letter are read together a row at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::myszkowski::Myszkowski;
+    ///
+    /// let m = Myszkowski::new("tomato").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key is empty or not alphanumeric.
+    ///
+    pub fn new(key: &str) -> Result<Self, String> {
+        let order = validate_key(key)?;
+        let key = key.to_ascii_uppercase().chars().collect::<Vec<char>>();
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for col in order {
+            match groups.last_mut() {
+                Some(group) if key[group[0]] == key[col] => group.push(col),
+                _ => groups.push(vec![col]),
+            }
+        }
+        Ok(Myszkowski { groups })
+    }
+
+    /// Enciphers a message with a myszkowski transposition cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::myszkowski::Myszkowski;
+    ///
+    /// let m = Myszkowski::new("tomato").unwrap();
+    /// assert_eq!(
+    ///     "ROFOACDTEDSEEEACWEIVRLENE",
+    ///     m.encipher("WEAREDISCOVEREDFLEEATONCE").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let text = plaintext.chars().collect::<Vec<char>>();
+        Ok(Grid::transpose(&text, &self.order(text.len())))
+    }
+
+    /// Deciphers a message with a myszkowski transposition cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::myszkowski::Myszkowski;
+    ///
+    /// let m = Myszkowski::new("tomato").unwrap();
+    /// assert_eq!(
+    ///     "WEAREDISCOVEREDFLEEATONCE",
+    ///     m.decipher("ROFOACDTEDSEEEACWEIVRLENE").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let text = ciphertext.chars().collect::<Vec<char>>();
+        Ok(Grid::restore(&text, &self.order(text.len())))
+    }
+
+    // Calculates the order the characters of the text are read out in.
+    fn order(&self, length: usize) -> Vec<usize> {
+        let width = self.groups.iter().map(|g| g.len()).sum();
+        Grid::new(width, length).read_columns(&self.groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Myszkowski;
+
+    #[test]
+    fn valid_key() {
+        assert!(Myszkowski::new("tomato").is_ok());
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(Myszkowski::new("tom ato").is_err());
+    }
+
+    #[test]
+    fn repeated_letters_grouped() {
+        let m = Myszkowski::new("tomato").unwrap();
+        assert_eq!(vec![vec![3], vec![2], vec![1, 5], vec![0, 4]], m.groups);
+    }
+
+    #[test]
+    fn encipher() {
+        let m = Myszkowski::new("tomato").unwrap();
+        assert_eq!(
+            "ROFOACDTEDSEEEACWEIVRLENE",
+            m.encipher("WEAREDISCOVEREDFLEEATONCE").unwrap()
+        );
+    }
+
+    #[test]
+    fn decipher() {
+        let m = Myszkowski::new("tomato").unwrap();
+        assert_eq!(
+            "WEAREDISCOVEREDFLEEATONCE",
+            m.decipher("ROFOACDTEDSEEEACWEIVRLENE").unwrap()
+        );
+    }
+
+    #[test]
+    fn unique_key_is_columnar() {
+        let m = Myszkowski::new("zebras").unwrap();
+        assert_eq!(
+            "EVLNACDTESEAROFODEECWIREE",
+            m.encipher("WEAREDISCOVEREDFLEEATONCE").unwrap()
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use common::{column_order, keyed_square};
+
+/// Nihilist Substitution Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct NihilistSubstitution {
+    square: Vec<char>,
+    key: Vec<u32>,
+}
+
+impl NihilistSubstitution {
+    /// Initializes a nihilist substitution cipher with a keyword for the polybius
+    /// square and a key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::nihilist::NihilistSubstitution;
+    ///
+    /// let n = NihilistSubstitution::new("zebras", "russian").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if either key is not alphabetic or the key is empty.
+    ///
+    pub fn new(square_key: &str, key: &str) -> Result<Self, String> {
+        if key.is_empty() {
+            return Err(String::from("Key must not be empty"));
+        }
+        for c in square_key.chars().chain(key.chars()) {
+            if c.is_ascii_alphabetic() {
+                continue;
+            }
+            return Err(String::from("Keys must be alphabetic"));
+        }
+
+        let square = keyed_square(square_key);
+        let key = NihilistSubstitution::to_numbers(&square, key);
+        Ok(NihilistSubstitution { square, key })
+    }
+
+    /// Enciphers a message with a nihilist substitution cipher.
+    ///
+    /// Only the letters of the message are enciphered and J is treated as I. The
+    /// ciphertext is a list of numbers separated by spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::nihilist::NihilistSubstitution;
+    ///
+    /// let n = NihilistSubstitution::new("zebras", "russian").unwrap();
+    /// assert_eq!(
+    ///     "37 106 62 36 67 47 86 26 104 53 62 77 27 55 57 66 55 36 54 27",
+    ///     n.encipher("Dynamite Winter Palace").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        Ok(NihilistSubstitution::to_numbers(&self.square, plaintext)
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n + self.key[i % self.key.len()]).to_string())
+            .collect::<Vec<String>>()
+            .join(" "))
+    }
+
+    /// Deciphers a message with a nihilist substitution cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::nihilist::NihilistSubstitution;
+    ///
+    /// let n = NihilistSubstitution::new("zebras", "russian").unwrap();
+    /// assert_eq!(
+    ///     "DYNAMITEWINTERPALACE",
+    ///     n.decipher("37 106 62 36 67 47 86 26 104 53 62 77 27 55 57 66 55 36 54 27")
+    ///         .unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the ciphertext contains anything other than numbers
+    /// separated by whitespace, or a number that does not decipher to a position
+    /// in the square.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let mut plaintext = String::new();
+
+        for (i, group) in ciphertext.split_whitespace().enumerate() {
+            let number = group
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid number in ciphertext: {}", group))?;
+            let coordinate = number
+                .checked_sub(self.key[i % self.key.len()])
+                .filter(|n| (1..=5).contains(&(n / 10)) && (1..=5).contains(&(n % 10)))
+                .ok_or_else(|| format!("Number out of range in ciphertext: {}", group))?;
+
+            plaintext.push(
+                self.square[(coordinate / 10 - 1) as usize * 5 + (coordinate % 10 - 1) as usize],
+            );
+        }
+
+        Ok(plaintext)
+    }
+
+    // Converts the letters of the text to their row and column in the square.
+    fn to_numbers(square: &[char], text: &str) -> Vec<u32> {
+        text.chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .map(|c| match c.to_ascii_uppercase() {
+                'J' => 'I',
+                c => c,
+            })
+            .map(|c| {
+                let p = square.iter().position(|&s| s == c).unwrap() as u32;
+                (p / 5 + 1) * 10 + p % 5 + 1
+            })
+            .collect()
+    }
+}
+
+/// Nihilist Transposition Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct NihilistTransposition {
+    order: Vec<usize>,
+}
+
+impl NihilistTransposition {
+    /// Initializes a nihilist transposition cipher with a supplied key.
+    ///
+    /// The message is written by rows into a square with a side as long as the
+    /// key, then both the columns and the rows are rearranged in the alphabetical
+    /// order of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::nihilist::NihilistTransposition;
+    ///
+    /// let n = NihilistTransposition::new("lion").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key is empty or not alphabetic.
+    ///
+    pub fn new(key: &str) -> Result<Self, String> {
+        if key.is_empty() {
+            return Err(String::from("Key must not be empty"));
+        }
+        for c in key.chars() {
+            if c.is_ascii_alphabetic() {
+                continue;
+            }
+            return Err(String::from("Key must be alphabetic"));
+        }
+
+        Ok(NihilistTransposition {
+            order: column_order(key),
+        })
+    }
+
+    /// Enciphers a message with a nihilist transposition cipher.
+    ///
+    /// Messages longer than the square are enciphered one square at a time. The
+    /// last square is left incomplete rather than padded, and its empty cells
+    /// are skipped when it is read out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::nihilist::NihilistTransposition;
+    ///
+    /// let n = NihilistTransposition::new("lion").unwrap();
+    /// assert_eq!(" ehtlCsodieg erb", n.encipher("Close the bridge").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let area = self.order.len() * self.order.len();
+        Ok(plaintext
+            .chars()
+            .collect::<Vec<char>>()
+            .chunks(area)
+            .flat_map(|block| self.cells(block.len()).into_iter().map(move |p| block[p]))
+            .collect())
+    }
+
+    /// Deciphers a message with a nihilist transposition cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::nihilist::NihilistTransposition;
+    ///
+    /// let n = NihilistTransposition::new("lion").unwrap();
+    /// assert_eq!("Close the bridge", n.decipher(" ehtlCsodieg erb").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let area = self.order.len() * self.order.len();
+        let mut plaintext = String::new();
+
+        for block in ciphertext.chars().collect::<Vec<char>>().chunks(area) {
+            let mut square = vec![' '; block.len()];
+            for (&p, &c) in self.cells(block.len()).iter().zip(block) {
+                square[p] = c;
+            }
+            plaintext.extend(square);
+        }
+
+        Ok(plaintext)
+    }
+
+    // The cells of a square holding the given number of characters, in the order
+    // they are read out: rows and columns both taken in key order.
+    fn cells(&self, len: usize) -> Vec<usize> {
+        let size = self.order.len();
+        self.order
+            .iter()
+            .flat_map(|&r| self.order.iter().map(move |&c| r * size + c))
+            .filter(|&p| p < len)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NihilistSubstitution, NihilistTransposition};
+
+    #[test]
+    fn valid_keys() {
+        assert!(NihilistSubstitution::new("zebras", "russian").is_ok());
+    }
+
+    #[test]
+    fn invalid_keys() {
+        assert!(NihilistSubstitution::new("zebras", "russ1an").is_err());
+    }
+
+    #[test]
+    fn empty_key() {
+        assert!(NihilistSubstitution::new("zebras", "").is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let n = NihilistSubstitution::new("zebras", "russian").unwrap();
+        assert_eq!(
+            "37 106 62 36 67 47 86 26 104 53 62 77 27 55 57 66 55 36 54 27",
+            n.encipher("Dynamite Winter Palace").unwrap()
+        );
+    }
+
+    #[test]
+    fn decipher() {
+        let n = NihilistSubstitution::new("zebras", "russian").unwrap();
+        assert_eq!(
+            "DYNAMITEWINTERPALACE",
+            n.decipher("37 106 62 36 67 47 86 26 104 53 62 77 27 55 57 66 55 36 54 27")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn decipher_irregular_whitespace() {
+        let n = NihilistSubstitution::new("zebras", "russian").unwrap();
+        assert_eq!("DYN", n.decipher(" 37\n106   62 ").unwrap());
+    }
+
+    #[test]
+    fn decipher_invalid_number() {
+        let n = NihilistSubstitution::new("zebras", "russian").unwrap();
+        assert!(n.decipher("37 1O6 62").is_err());
+    }
+
+    #[test]
+    fn decipher_out_of_range() {
+        let n = NihilistSubstitution::new("zebras", "russian").unwrap();
+        assert!(n.decipher("37 10 62").is_err());
+    }
+
+    #[test]
+    fn transposition_invalid_key() {
+        assert!(NihilistTransposition::new("l1on").is_err());
+    }
+
+    #[test]
+    fn transposition_encipher() {
+        let n = NihilistTransposition::new("lion").unwrap();
+        assert_eq!(" ehtlCsodieg erb", n.encipher("Close the bridge").unwrap());
+    }
+
+    #[test]
+    fn transposition_decipher() {
+        let n = NihilistTransposition::new("lion").unwrap();
+        assert_eq!("Close the bridge", n.decipher(" ehtlCsodieg erb").unwrap());
+    }
+
+    #[test]
+    fn transposition_multiple_squares() {
+        let n = NihilistTransposition::new("bear").unwrap();
+        let ciphertext = n.encipher("Meet me at the usual place at noon").unwrap();
+        assert_eq!(34, ciphertext.chars().count());
+        assert_eq!(
+            "Meet me at the usual place at noon",
+            n.decipher(&ciphertext).unwrap()
+        );
+    }
+
+    #[test]
+    fn transposition_keeps_trailing_spaces() {
+        let n = NihilistTransposition::new("lion").unwrap();
+        let ciphertext = n.encipher("Hold the line  ").unwrap();
+        assert_eq!("Hold the line  ", n.decipher(&ciphertext).unwrap());
+    }
+}
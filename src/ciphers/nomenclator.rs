@@ -0,0 +1,285 @@
+use std::cmp::Reverse;
+
+use ciphers::substitution::Substitution;
+
+/// Nomenclator Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Nomenclator {
+    entries: Vec<(Vec<String>, String, String)>,
+    substitution: Substitution,
+}
+
+impl Nomenclator {
+    /// Initializes a nomenclator with a codebook and the substitute alphabet for
+    /// the letters of words that are not in the codebook.
+    ///
+    /// The codebook has an entry on each line, written as the word or phrase and
+    /// its code group separated by a comma, or by the last space on the line when
+    /// no number follows the last comma. Code groups are numbers, so that they
+    /// cannot be mistaken for substituted words.
+    /// Empty lines and lines starting with # are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::nomenclator::Nomenclator;
+    ///
+    /// let codebook = "the King,107\nParis,214\nthe Queen of Scots 88\n";
+    /// let n = Nomenclator::new(codebook, "NAKYQRTXBZPFIVEJSDCHGOUMWL").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if an entry has no code group, a code group is not a
+    /// number, an entry or code group is used twice, or the substitute alphabet
+    /// is not valid.
+    ///
+    pub fn new(codebook: &str, key: &str) -> Result<Self, String> {
+        let mut entries: Vec<(Vec<String>, String, String)> = Vec::new();
+        for line in codebook.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            // The comma form is only used when a number follows the last comma, so
+            // that entries written with a space may themselves hold commas.
+            let split = line
+                .rfind(',')
+                .filter(|&i| Nomenclator::is_code(line[i + 1..].trim()))
+                .or_else(|| line.rfind(char::is_whitespace))
+                .ok_or_else(|| format!("Entry '{}' has no code group", line))?;
+            let (entry, code) = (line[..split].trim(), line[split + 1..].trim());
+
+            let words = Nomenclator::words(entry);
+            if words.is_empty() {
+                return Err(format!("Code group {} has no entry", code));
+            }
+            if !Nomenclator::is_code(code) {
+                return Err(format!("Code group for '{}' must be a number", entry));
+            }
+            if entries.iter().any(|e| e.0 == words || e.1 == code) {
+                return Err(format!("Entry '{}' is in the codebook twice", entry));
+            }
+            entries.push((words, String::from(code), String::from(entry)));
+        }
+
+        // Longer entries are tried first, so that a phrase is coded as a whole
+        // rather than by the words it starts with.
+        entries.sort_by_key(|e| Reverse(e.0.len()));
+
+        Ok(Nomenclator {
+            entries,
+            substitution: Substitution::new(key)?,
+        })
+    }
+
+    /// Enciphers a message with a nomenclator.
+    ///
+    /// Words and phrases in the codebook are replaced by their code groups, with
+    /// punctuation before and after them kept, and other words are enciphered by
+    /// the substitution. Words are separated by single spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::nomenclator::Nomenclator;
+    ///
+    /// let codebook = "the King,107\nParis,214\nthe Queen of Scots 88\n";
+    /// let n = Nomenclator::new(codebook, "NAKYQRTXBZPFIVEJSDCHGOUMWL").unwrap();
+    /// assert_eq!(
+    ///     "88 cxnff dqnkx 214.",
+    ///     n.encipher("The Queen of Scots shall reach Paris.").unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if a word that is not in the codebook has digits, as it
+    /// could not be told apart from a code group.
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let tokens = plaintext.split_whitespace().collect::<Vec<&str>>();
+        let mut ciphertext = Vec::new();
+        let mut i = 0;
+        while i < tokens.len() {
+            let found = self.entries.iter().find(|e| {
+                i + e.0.len() <= tokens.len()
+                    && Nomenclator::words(&tokens[i..i + e.0.len()].join(" ")) == e.0
+            });
+            match found {
+                Some((words, code, _)) => {
+                    let first = tokens[i];
+                    let start = first.trim_start_matches(|c: char| !c.is_alphanumeric());
+                    let last = tokens[i + words.len() - 1];
+                    let end = last.trim_end_matches(|c: char| !c.is_alphanumeric());
+                    ciphertext.push(format!(
+                        "{}{}{}",
+                        &first[..first.len() - start.len()],
+                        code,
+                        &last[end.len()..]
+                    ));
+                    i += words.len();
+                }
+                None => {
+                    if tokens[i].chars().any(|c| c.is_ascii_digit()) {
+                        return Err(format!("'{}' is not in the codebook", tokens[i]));
+                    }
+                    ciphertext.push(self.substitution.encipher(tokens[i])?);
+                    i += 1;
+                }
+            }
+        }
+        Ok(ciphertext.join(" "))
+    }
+
+    /// Deciphers a message with a nomenclator, writing code groups as their
+    /// entries appear in the codebook.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::nomenclator::Nomenclator;
+    ///
+    /// let codebook = "the King,107\nParis,214\nthe Queen of Scots 88\n";
+    /// let n = Nomenclator::new(codebook, "NAKYQRTXBZPFIVEJSDCHGOUMWL").unwrap();
+    /// assert_eq!(
+    ///     "the Queen of Scots shall reach Paris.",
+    ///     n.decipher("88 cxnff dqnkx 214.").unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if a code group is not in the codebook.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let mut plaintext = Vec::new();
+        for token in ciphertext.split_whitespace() {
+            let body = token.trim_start_matches(|c: char| !c.is_alphanumeric());
+            let digits = body.len() - body.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if digits == 0 {
+                plaintext.push(self.substitution.decipher(token)?);
+                continue;
+            }
+            let (prefix, code) = (&token[..token.len() - body.len()], &body[..digits]);
+            match self.entries.iter().find(|e| e.1 == code) {
+                Some(e) => plaintext.push(format!("{}{}{}", prefix, e.2, &body[digits..])),
+                None => return Err(format!("Code group {} is not in the codebook", code)),
+            }
+        }
+        Ok(plaintext.join(" "))
+    }
+
+    // Whether a code group is a number.
+    fn is_code(code: &str) -> bool {
+        !code.is_empty() && code.chars().all(|c| c.is_ascii_digit())
+    }
+
+    // The words of a text in lower case, without punctuation.
+    fn words(text: &str) -> Vec<String> {
+        text.split_whitespace()
+            .map(|w| {
+                w.chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .flat_map(|c| c.to_lowercase())
+                    .collect::<String>()
+            })
+            .filter(|w| !w.is_empty())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Nomenclator;
+
+    const KEY: &str = "NAKYQRTXBZPFIVEJSDCHGOUMWL";
+    const CODEBOOK: &str = "# Persons\n\
+        the King,107\n\
+        the Queen of Scots, 88\n\
+        Walsingham 301\n\
+        \n\
+        # Places\n\
+        Paris,214\n\
+        Chartley,215\n";
+
+    #[test]
+    fn missing_code() {
+        assert!(Nomenclator::new("Paris", KEY).is_err());
+    }
+
+    #[test]
+    fn code_not_a_number() {
+        assert!(Nomenclator::new("Paris,P1", KEY).is_err());
+    }
+
+    #[test]
+    fn repeated_code() {
+        assert!(Nomenclator::new("Paris,214\nRome,214", KEY).is_err());
+        assert!(Nomenclator::new("Paris,214\nparis,215", KEY).is_err());
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(Nomenclator::new(CODEBOOK, "ABC").is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let n = Nomenclator::new(CODEBOOK, KEY).unwrap();
+        assert_eq!(
+            "Bv 215, 88 udehq he 301",
+            n.encipher("In Chartley, the queen of Scots wrote to Walsingham")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn longest_entry_first() {
+        let n = Nomenclator::new("the Queen,5\nthe Queen of Scots,88", KEY).unwrap();
+        assert_eq!(
+            "5 ed 88",
+            n.encipher("the Queen or the Queen of Scots").unwrap()
+        );
+    }
+
+    #[test]
+    fn decipher() {
+        let n = Nomenclator::new(CODEBOOK, KEY).unwrap();
+        assert_eq!(
+            "In Chartley, the Queen of Scots wrote to Walsingham",
+            n.decipher("Bv 215, 88 udehq he 301").unwrap()
+        );
+    }
+
+    #[test]
+    fn unknown_code() {
+        let n = Nomenclator::new(CODEBOOK, KEY).unwrap();
+        assert_eq!(
+            Err(String::from("Code group 999 is not in the codebook")),
+            n.decipher("Bv 999")
+        );
+    }
+
+    #[test]
+    fn comma_in_spaced_entry() {
+        let n = Nomenclator::new("Mary, Queen of Scots 88\nParis,214", KEY).unwrap();
+        assert_eq!("88 214", n.encipher("Mary, Queen of Scots Paris").unwrap());
+        assert_eq!("Mary, Queen of Scots Paris", n.decipher("88 214").unwrap());
+    }
+
+    #[test]
+    fn surrounding_punctuation() {
+        let n = Nomenclator::new(CODEBOOK, KEY).unwrap();
+        let ciphertext = n.encipher("(Paris) and \"the King\"").unwrap();
+        assert_eq!("(214) nvy \"107\"", ciphertext);
+        assert_eq!("(Paris) and \"the King\"", n.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn digits_outside_codebook() {
+        let n = Nomenclator::new(CODEBOOK, KEY).unwrap();
+        assert!(n.encipher("Meet at 9").is_err());
+    }
+}
@@ -0,0 +1,319 @@
+/// One-Time Pad Cipher
+///
+/// The pad is a run of letters that are added to the letters of each message
+/// modulo 26. A message is enciphered at an offset into the pad and the pad
+/// remembers how far it has been used, so that no part of it is used twice.
+///
+/// The struct is generated through the new() function.
+///
+pub struct OneTimePad {
+    pad: Vec<u8>,
+    used: usize,
+}
+
+impl OneTimePad {
+    /// Initializes a one-time pad with the letters of a pad, which may be written
+    /// in groups separated by spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::otp::OneTimePad;
+    ///
+    /// let p = OneTimePad::new("XMCKL NQWTZ").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the pad is empty or has characters other than letters
+    /// and spaces.
+    ///
+    pub fn new(pad: &str) -> Result<Self, String> {
+        let mut letters = Vec::new();
+        for c in pad.chars().filter(|c| !c.is_whitespace()) {
+            if !c.is_ascii_alphabetic() {
+                return Err(String::from("Pad must be letters"));
+            }
+            letters.push(c.to_ascii_uppercase() as u8 - 65);
+        }
+        if letters.is_empty() {
+            return Err(String::from("Pad must not be empty"));
+        }
+        Ok(OneTimePad {
+            pad: letters,
+            used: 0,
+        })
+    }
+
+    /// The offset of the first letter of the pad that has not been used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::otp::OneTimePad;
+    ///
+    /// let mut p = OneTimePad::new("XMCKL NQWTZ").unwrap();
+    /// p.encipher("Hello", 0).unwrap();
+    /// assert_eq!(5, p.offset());
+    /// ```
+    ///
+    pub fn offset(&self) -> usize {
+        self.used
+    }
+
+    /// Enciphers a message with the pad, starting at an offset into the pad.
+    ///
+    /// Only letters use the pad and the case of each letter is kept. The pad is
+    /// marked as used up to the end of the message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::otp::OneTimePad;
+    ///
+    /// let mut p = OneTimePad::new("XMCKL NQWTZ").unwrap();
+    /// assert_eq!("EQNVZ", p.encipher("HELLO", 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the offset is in a part of the pad that has already
+    /// been used, or the rest of the pad is shorter than the message.
+    ///
+    pub fn encipher(&mut self, plaintext: &str, offset: usize) -> Result<String, String> {
+        if offset < self.used {
+            return Err(String::from("Pad has already been used at this offset"));
+        }
+        let ciphertext = self.crypt(plaintext, offset, |p, k| p + k)?;
+        self.used = offset
+            + plaintext
+                .chars()
+                .filter(|c| c.is_ascii_alphabetic())
+                .count();
+        Ok(ciphertext)
+    }
+
+    /// Deciphers a message with the pad, starting at the offset the message was
+    /// enciphered at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::otp::OneTimePad;
+    ///
+    /// let p = OneTimePad::new("XMCKL NQWTZ").unwrap();
+    /// assert_eq!("HELLO", p.decipher("EQNVZ", 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the rest of the pad is shorter than the message.
+    ///
+    pub fn decipher(&self, ciphertext: &str, offset: usize) -> Result<String, String> {
+        self.crypt(ciphertext, offset, |c, k| c + 26 - k)
+    }
+
+    // Combines each letter of the text with the next letter of the pad.
+    fn crypt<F>(&self, text: &str, offset: usize, combine: F) -> Result<String, String>
+    where
+        F: Fn(u8, u8) -> u8,
+    {
+        let length = text.chars().filter(|c| c.is_ascii_alphabetic()).count();
+        if offset + length > self.pad.len() {
+            return Err(String::from("Pad is shorter than the message"));
+        }
+
+        let mut key = self.pad[offset..].iter();
+        Ok(text
+            .chars()
+            .map(|c| {
+                let base = match c {
+                    'A'..='Z' => 65,
+                    'a'..='z' => 97,
+                    _ => return c,
+                };
+                let k = *key.next().unwrap();
+                (combine(c as u8 - base, k) % 26 + base) as char
+            })
+            .collect())
+    }
+}
+
+/// Byte One-Time Pad Cipher
+///
+/// The pad is a run of bytes that are combined with the bytes of each message by
+/// exclusive or. A message is enciphered at an offset into the pad and the pad
+/// remembers how far it has been used, so that no part of it is used twice.
+///
+/// The struct is generated through the new() function.
+///
+pub struct BytePad {
+    pad: Vec<u8>,
+    used: usize,
+}
+
+impl BytePad {
+    /// Initializes a one-time pad with the bytes of a pad.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::otp::BytePad;
+    ///
+    /// let p = BytePad::new(&[0x5a, 0x13, 0xc4, 0x7e]).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the pad is empty.
+    ///
+    pub fn new(pad: &[u8]) -> Result<Self, String> {
+        if pad.is_empty() {
+            return Err(String::from("Pad must not be empty"));
+        }
+        Ok(BytePad {
+            pad: pad.to_vec(),
+            used: 0,
+        })
+    }
+
+    /// The offset of the first byte of the pad that has not been used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::otp::BytePad;
+    ///
+    /// let mut p = BytePad::new(&[0x5a, 0x13, 0xc4, 0x7e]).unwrap();
+    /// p.encipher(b"hi", 1).unwrap();
+    /// assert_eq!(3, p.offset());
+    /// ```
+    ///
+    pub fn offset(&self) -> usize {
+        self.used
+    }
+
+    /// Enciphers a message with the pad, starting at an offset into the pad. The
+    /// pad is marked as used up to the end of the message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::otp::BytePad;
+    ///
+    /// let mut p = BytePad::new(&[0x5a, 0x13, 0xc4, 0x7e]).unwrap();
+    /// assert_eq!(vec![0x32, 0x7a], p.encipher(b"hi", 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the offset is in a part of the pad that has already
+    /// been used, or the rest of the pad is shorter than the message.
+    ///
+    pub fn encipher(&mut self, plaintext: &[u8], offset: usize) -> Result<Vec<u8>, String> {
+        if offset < self.used {
+            return Err(String::from("Pad has already been used at this offset"));
+        }
+        let ciphertext = self.crypt(plaintext, offset)?;
+        self.used = offset + plaintext.len();
+        Ok(ciphertext)
+    }
+
+    /// Deciphers a message with the pad, starting at the offset the message was
+    /// enciphered at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::otp::BytePad;
+    ///
+    /// let p = BytePad::new(&[0x5a, 0x13, 0xc4, 0x7e]).unwrap();
+    /// assert_eq!(b"hi".to_vec(), p.decipher(&[0x32, 0x7a], 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the rest of the pad is shorter than the message.
+    ///
+    pub fn decipher(&self, ciphertext: &[u8], offset: usize) -> Result<Vec<u8>, String> {
+        self.crypt(ciphertext, offset)
+    }
+
+    // Combines each byte of the text with the next byte of the pad.
+    fn crypt(&self, text: &[u8], offset: usize) -> Result<Vec<u8>, String> {
+        if offset + text.len() > self.pad.len() {
+            return Err(String::from("Pad is shorter than the message"));
+        }
+        Ok(text
+            .iter()
+            .zip(self.pad[offset..].iter())
+            .map(|(b, k)| b ^ k)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BytePad, OneTimePad};
+
+    #[test]
+    fn invalid_pad() {
+        assert!(OneTimePad::new("XMCKL-NQWTZ").is_err());
+        assert!(BytePad::new(&[]).is_err());
+    }
+
+    #[test]
+    fn empty_pad() {
+        assert!(OneTimePad::new("").is_err());
+        assert!(OneTimePad::new("   ").is_err());
+        assert!(BytePad::new(&[]).is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let mut p = OneTimePad::new("xmckl nqwtz").unwrap();
+        assert_eq!("Eqnvz, leq!", p.encipher("Hello, you!", 0).unwrap());
+    }
+
+    #[test]
+    fn decipher() {
+        let p = OneTimePad::new("xmckl nqwtz").unwrap();
+        assert_eq!("Hello, you!", p.decipher("Eqnvz, leq!", 0).unwrap());
+    }
+
+    #[test]
+    fn short_pad() {
+        let mut p = OneTimePad::new("XMCKL").unwrap();
+        assert!(p.encipher("Hello you", 0).is_err());
+        assert!(p.decipher("EQNVZ", 1).is_err());
+        assert_eq!(0, p.offset());
+    }
+
+    #[test]
+    fn reused_pad() {
+        let mut p = OneTimePad::new("XMCKL NQWTZ").unwrap();
+        p.encipher("HELLO", 0).unwrap();
+        assert!(p.encipher("AGAIN", 3).is_err());
+        assert_eq!("MDTS", p.encipher("WHAT", 6).unwrap());
+        assert_eq!(10, p.offset());
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let pad = (0..=255).collect::<Vec<u8>>();
+        let mut p = BytePad::new(&pad).unwrap();
+        let first = p.encipher(b"Attack at dawn", 0).unwrap();
+        let second = p.encipher(b"Retreat", p.offset()).unwrap();
+        assert_eq!(b"Attack at dawn".to_vec(), p.decipher(&first, 0).unwrap());
+        assert_eq!(b"Retreat".to_vec(), p.decipher(&second, 14).unwrap());
+    }
+
+    #[test]
+    fn bytes_reused_pad() {
+        let mut p = BytePad::new(&[1, 2, 3, 4, 5, 6]).unwrap();
+        p.encipher(b"abc", 2).unwrap();
+        assert!(p.encipher(b"d", 4).is_err());
+        assert!(p.encipher(b"de", 5).is_err());
+        assert!(p.encipher(b"d", 5).is_ok());
+    }
+}
@@ -0,0 +1,187 @@
+//! Drawing of Pigpen ciphertext.
+//!
+//! The Pigpen cipher itself is `Substitution::pigpen`, which writes each letter as
+//! a glyph name from `PIGPEN`. This module draws those glyphs as an SVG image
+//! so the ciphertext can be printed.
+//!
+//! ```
+//! use kryptos::ciphers::pigpen::render_svg;
+//! use kryptos::ciphers::substitution::Substitution;
+//!
+//! let ciphertext = Substitution::pigpen().encipher("Meet at noon").unwrap();
+//! let svg = render_svg(&ciphertext);
+//! assert!(svg.starts_with("<svg"));
+//! ```
+
+use ciphers::substitution::PIGPEN;
+
+// The size of a glyph and the space each takes up on the page.
+const SIZE: usize = 30;
+const CELL: usize = 40;
+
+// The lines of a glyph, each a list of points to draw through.
+type Lines = Vec<Vec<(usize, usize)>>;
+
+/// Draws Pigpen ciphertext as an SVG image.
+///
+/// Each glyph is drawn in a 40 pixel cell, whitespace leaves an empty cell and
+/// each line of the ciphertext is drawn as a row. Other characters are written
+/// as text.
+///
+/// # Examples
+///
+/// ```
+/// use kryptos::ciphers::pigpen::render_svg;
+///
+/// let svg = render_svg("[pigpen-e][pigpen-n]");
+/// assert_eq!(1, svg.matches("<circle").count());
+/// ```
+///
+pub fn render_svg(ciphertext: &str) -> String {
+    let mut shapes = Vec::new();
+    let mut width = 0;
+    let lines = ciphertext.lines().collect::<Vec<&str>>();
+
+    for (row, line) in lines.iter().enumerate() {
+        let mut col = 0;
+        let mut rest = *line;
+        while let Some(c) = rest.chars().next() {
+            let (x, y) = (
+                col * CELL + (CELL - SIZE) / 2,
+                row * CELL + (CELL - SIZE) / 2,
+            );
+            match PIGPEN.iter().position(|g| rest.starts_with(g)) {
+                Some(letter) => {
+                    shapes.push(glyph(letter, x, y));
+                    rest = &rest[PIGPEN[letter].len()..];
+                }
+                None => {
+                    if !c.is_whitespace() {
+                        shapes.push(text(c, x, y));
+                    }
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+            col += 1;
+        }
+        width = width.max(col);
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n{}</svg>\n",
+        width * CELL,
+        lines.len() * CELL,
+        shapes.concat()
+    )
+}
+
+// Draws the glyph of a letter with its top left corner at a point. The letters
+// A to R sit in the cells of a noughts and crosses grid, and S to Z in the
+// quarters of an X, with the second of each pair of grids dotted.
+fn glyph(letter: usize, x: usize, y: usize) -> String {
+    let s = SIZE;
+    let h = SIZE / 2;
+    let (points, dot): (Lines, (usize, usize)) = if letter < 18 {
+        let (row, col) = (letter % 9 / 3, letter % 3);
+        let mut sides = Vec::new();
+        if row > 0 {
+            sides.push(vec![(0, 0), (s, 0)]);
+        }
+        if col < 2 {
+            sides.push(vec![(s, 0), (s, s)]);
+        }
+        if row < 2 {
+            sides.push(vec![(0, s), (s, s)]);
+        }
+        if col > 0 {
+            sides.push(vec![(0, 0), (0, s)]);
+        }
+        (sides, (h, h))
+    } else {
+        match (letter - 18) % 4 {
+            0 => (vec![vec![(0, 0), (h, s), (s, 0)]], (h, s / 3)),
+            1 => (vec![vec![(0, 0), (s, h), (0, s)]], (s / 3, h)),
+            2 => (vec![vec![(s, 0), (0, h), (s, s)]], (s - s / 3, h)),
+            _ => (vec![vec![(0, s), (h, 0), (s, s)]], (h, s - s / 3)),
+        }
+    };
+
+    let d = points
+        .iter()
+        .map(|line| {
+            line.iter()
+                .enumerate()
+                .map(|(i, &(px, py))| {
+                    format!("{}{} {}", if i == 0 { "M" } else { "L" }, x + px, y + py)
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let mut shape = format!(
+        "<path d=\"{}\" fill=\"none\" stroke=\"black\" stroke-width=\"2\"/>\n",
+        d
+    );
+    if (9..18).contains(&letter) || letter >= 22 {
+        shape.push_str(&format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"2.5\"/>\n",
+            x + dot.0,
+            y + dot.1
+        ));
+    }
+    shape
+}
+
+// Writes a character that is not a glyph in the cell.
+fn text(c: char, x: usize, y: usize) -> String {
+    let c = match c {
+        '<' => String::from("&lt;"),
+        '>' => String::from("&gt;"),
+        '&' => String::from("&amp;"),
+        c => c.to_string(),
+    };
+    format!(
+        "<text x=\"{}\" y=\"{}\" font-size=\"{}\" text-anchor=\"middle\">{}</text>\n",
+        x + SIZE / 2,
+        y + SIZE,
+        SIZE,
+        c
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_svg;
+
+    #[test]
+    fn grid_letters() {
+        // A has sides on the right and bottom, and E is a closed square.
+        let svg = render_svg("[pigpen-a][pigpen-e]");
+        assert!(svg.contains("<path d=\"M35 5 L35 35 M5 35 L35 35\""));
+        assert!(svg.contains("<path d=\"M45 5 L75 5 M75 5 L75 35 M45 35 L75 35 M45 5 L45 35\""));
+        assert!(!svg.contains("<circle"));
+    }
+
+    #[test]
+    fn x_letters() {
+        let svg = render_svg("[pigpen-s][pigpen-w]");
+        assert!(svg.contains("<path d=\"M5 5 L20 35 L35 5\""));
+        assert!(svg.contains("<path d=\"M45 5 L60 35 L75 5\""));
+        assert!(svg.contains("<circle cx=\"60\" cy=\"15\" r=\"2.5\"/>"));
+    }
+
+    #[test]
+    fn rows_and_spaces() {
+        let svg = render_svg("[pigpen-j] [pigpen-k]\n[pigpen-z]!");
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"120\" height=\"80\">"));
+        assert_eq!(3, svg.matches("<circle").count());
+        assert!(svg.contains(">!</text>"));
+    }
+
+    #[test]
+    fn escapes_text() {
+        assert!(render_svg("<").contains(">&lt;</text>"));
+    }
+}
@@ -0,0 +1,186 @@
+/// A cipher that shifts each letter along an alphabet by the next shift of a
+/// repeating key, such as the Caesar and Vigenere ciphers.
+pub trait ShiftCipher {
+    /// The shifts of one period of the key, or Err() if the key cannot be
+    /// read as shifts.
+    fn shifts(&self) -> Result<Vec<u8>, String>;
+
+    /// The alphabet the letters are shifted along.
+    fn alphabet(&self) -> Vec<char>;
+}
+
+/// Progressive Key Cipher
+///
+/// Wraps a shift cipher so that its key is advanced by an increment after each
+/// period. A Vigenere key is advanced after each run through the keyword, and a
+/// Caesar rotation after every letter.
+///
+/// The struct is generated through the new() function.
+///
+pub struct Progressive {
+    shifts: Vec<u8>,
+    alphabet: Vec<char>,
+    increment: u8,
+}
+
+impl Progressive {
+    /// Initializes a progressive key cipher from a shift cipher and the increment
+    /// its key is advanced by after each period.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::progressive::Progressive;
+    /// use kryptos::ciphers::vigenere::Vigenere;
+    ///
+    /// let p = Progressive::new(Vigenere::new("key").unwrap(), 1).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the increment is a multiple of 26, which would never
+    /// advance the key, or the cipher's key is empty or cannot be read as shifts.
+    ///
+    pub fn new<C: ShiftCipher>(cipher: C, increment: u8) -> Result<Self, String> {
+        if increment.is_multiple_of(26) {
+            return Err(String::from("Increment must not be a multiple of 26"));
+        }
+        let shifts = cipher.shifts()?;
+        if shifts.is_empty() {
+            return Err(String::from("Key must have at least one shift"));
+        }
+        Ok(Progressive {
+            shifts,
+            alphabet: cipher.alphabet(),
+            increment,
+        })
+    }
+
+    /// Enciphers a message with a progressive key cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::progressive::Progressive;
+    /// use kryptos::ciphers::vigenere::Vigenere;
+    ///
+    /// let p = Progressive::new(Vigenere::new("key").unwrap(), 1).unwrap();
+    /// assert_eq!("Kxrlhj mz dndo", p.encipher("Attack at dawn").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        Ok(self.crypt(plaintext, false))
+    }
+
+    /// Deciphers a message with a progressive key cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::progressive::Progressive;
+    /// use kryptos::ciphers::vigenere::Vigenere;
+    ///
+    /// let p = Progressive::new(Vigenere::new("key").unwrap(), 1).unwrap();
+    /// assert_eq!("Attack at dawn", p.decipher("Kxrlhj mz dndo").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        Ok(self.crypt(ciphertext, true))
+    }
+
+    // Shifts each letter by the key shift for its position, advanced by the
+    // increment once for each period before it.
+    fn crypt(&self, text: &str, reverse: bool) -> String {
+        let (shifts, alphabet) = (&self.shifts, &self.alphabet);
+        let mut i = 0;
+
+        text.chars()
+            .map(|c| {
+                let upper = c.to_ascii_uppercase();
+                let index = match alphabet.iter().position(|&a| a == upper) {
+                    Some(index) if c.is_ascii_alphabetic() => index,
+                    _ => return c,
+                };
+                let period = (i / shifts.len()) % 26;
+                let shift =
+                    (shifts[i % shifts.len()] as usize + self.increment as usize * period) % 26;
+                i += 1;
+
+                let shifted = if reverse {
+                    alphabet[(index + 26 - shift) % 26]
+                } else {
+                    alphabet[(index + shift) % 26]
+                };
+                if c.is_ascii_lowercase() {
+                    shifted.to_ascii_lowercase()
+                } else {
+                    shifted
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Progressive;
+    use ciphers::caesar::Caesar;
+    use ciphers::trithemius::Trithemius;
+    use ciphers::vigenere::Vigenere;
+    use keygen;
+
+    #[test]
+    fn zero_increment() {
+        assert!(Progressive::new(Caesar::new(3).unwrap(), 26).is_err());
+    }
+
+    #[test]
+    fn empty_key() {
+        assert!(Progressive::new(Vigenere::new("").unwrap(), 1).is_err());
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(Progressive::new(Vigenere::new("é").unwrap(), 1).is_err());
+    }
+
+    #[test]
+    fn vigenere_encipher() {
+        let p = Progressive::new(Vigenere::new("abc").unwrap(), 2).unwrap();
+        assert_eq!("abccdeefg", p.encipher("aaaaaaaaa").unwrap());
+    }
+
+    #[test]
+    fn caesar_is_trithemius() {
+        let p = Progressive::new(Caesar::new(26).unwrap(), 1).unwrap();
+        let t = Trithemius::new(0, 1).unwrap();
+        let plaintext = "The quick brown fox jumps over the lazy dog";
+        assert_eq!(
+            t.encipher(plaintext).unwrap(),
+            p.encipher(plaintext).unwrap()
+        );
+    }
+
+    #[test]
+    fn key_wraps_around() {
+        let p = Progressive::new(Caesar::new(1).unwrap(), 25).unwrap();
+        assert_eq!("BAZYX", p.encipher("AAAAA").unwrap());
+        assert_eq!("AAAAA", p.decipher("BAZYX").unwrap());
+    }
+
+    #[test]
+    fn keyed_caesar() {
+        let alphabet = keygen::keyword_mixed("kryptos").unwrap();
+        let p = Progressive::new(Caesar::with_alphabet(1, &alphabet).unwrap(), 1).unwrap();
+        assert_eq!("RYP", p.encipher("KKK").unwrap());
+        assert_eq!("KKK", p.decipher("RYP").unwrap());
+    }
+
+    #[test]
+    fn round_trip() {
+        let p = Progressive::new(Vigenere::new("progressive").unwrap(), 7).unwrap();
+        let plaintext = "Many periods of a long message, 🖤 each shifted further";
+        let ciphertext = p.encipher(plaintext).unwrap();
+        assert_eq!(plaintext, p.decipher(&ciphertext).unwrap());
+    }
+}
@@ -0,0 +1,264 @@
+use common::{keyed_alphabet, ALPHABET};
+
+/// The four types of quagmire cipher and the keywords used for their alphabets.
+///
+/// - `One` keys the plaintext alphabet and uses a straight ciphertext alphabet.
+/// - `Two` uses a straight plaintext alphabet and keys the ciphertext alphabet.
+/// - `Three` keys both alphabets with the same keyword.
+/// - `Four` keys the plaintext and ciphertext alphabets with different keywords.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Variant<'a> {
+    One(&'a str),
+    Two(&'a str),
+    Three(&'a str),
+    Four(&'a str, &'a str),
+}
+
+/// Quagmire Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Quagmire {
+    plain: Vec<char>,
+    rows: Vec<(char, Vec<char>)>,
+}
+
+impl Quagmire {
+    /// Initializes a quagmire cipher with a variant, an indicator keyword and the
+    /// plaintext letter the indicator keyword is aligned under.
+    ///
+    /// Each letter of the indicator selects a ciphertext alphabet slid so that the
+    /// indicator letter sits under the alignment letter of the plaintext alphabet.
+    /// ACA puzzles usually align under plaintext A for types I and II and under
+    /// the first letter of the plaintext alphabet for types III and IV.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::quagmire::{Quagmire, Variant};
+    ///
+    /// let q = Quagmire::new(Variant::Three("kryptos"), "palimpsest", 'K').unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if a keyword is not alphabetic, the indicator is empty or
+    /// the alignment is not a letter.
+    ///
+    pub fn new(variant: Variant, indicator: &str, alignment: char) -> Result<Self, String> {
+        let (plain_key, cipher_key) = match variant {
+            Variant::One(key) => (key, ""),
+            Variant::Two(key) => ("", key),
+            Variant::Three(key) => (key, key),
+            Variant::Four(plain, cipher) => (plain, cipher),
+        };
+        for c in plain_key.chars().chain(cipher_key.chars()) {
+            if c.is_ascii_alphabetic() {
+                continue;
+            }
+            return Err(String::from("Keywords must be alphabetic"));
+        }
+        if indicator.is_empty() || !indicator.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(String::from("Indicator must be alphabetic"));
+        }
+        if !alignment.is_ascii_alphabetic() {
+            return Err(String::from("Alignment must be a letter"));
+        }
+
+        let plain = keyed_alphabet(plain_key, ALPHABET)
+            .chars()
+            .collect::<Vec<char>>();
+        let cipher = keyed_alphabet(cipher_key, ALPHABET)
+            .chars()
+            .collect::<Vec<char>>();
+        let position = Quagmire::index(&plain, alignment);
+
+        let rows = indicator
+            .chars()
+            .map(|k| {
+                let k = k.to_ascii_uppercase();
+                let offset = Quagmire::index(&cipher, k) + 26 - position;
+                (k, (0..26).map(|j| cipher[(j + offset) % 26]).collect())
+            })
+            .collect();
+
+        Ok(Quagmire { plain, rows })
+    }
+
+    /// Enciphers a message with a quagmire cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::quagmire::{Quagmire, Variant};
+    ///
+    /// let q = Quagmire::new(Variant::Three("kryptos"), "palimpsest", 'K').unwrap();
+    /// assert_eq!(
+    ///     "EMUFPHZLRFAXYUSDJKZLDKRNSHGNFIVJ",
+    ///     q.encipher("BETWEENSUBTLESHADINGANDTHEABSENC").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let mut row = 0;
+        Ok(plaintext
+            .chars()
+            .map(|c| {
+                if !c.is_ascii_alphabetic() {
+                    return c;
+                }
+                let cipher = &self.rows[row % self.rows.len()].1;
+                row += 1;
+                Quagmire::with_case(cipher[Quagmire::index(&self.plain, c)], c)
+            })
+            .collect())
+    }
+
+    /// Deciphers a message with a quagmire cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::quagmire::{Quagmire, Variant};
+    ///
+    /// let q = Quagmire::new(Variant::Three("kryptos"), "palimpsest", 'K').unwrap();
+    /// assert_eq!(
+    ///     "BETWEENSUBTLESHADINGANDTHEABSENC",
+    ///     q.decipher("EMUFPHZLRFAXYUSDJKZLDKRNSHGNFIVJ").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let mut row = 0;
+        Ok(ciphertext
+            .chars()
+            .map(|c| {
+                if !c.is_ascii_alphabetic() {
+                    return c;
+                }
+                let cipher = &self.rows[row % self.rows.len()].1;
+                row += 1;
+                Quagmire::with_case(self.plain[Quagmire::index(cipher, c)], c)
+            })
+            .collect())
+    }
+
+    /// Renders the tableau, with the plaintext alphabet on the first line and the
+    /// ciphertext alphabet for each indicator letter below it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::quagmire::{Quagmire, Variant};
+    ///
+    /// let q = Quagmire::new(Variant::One("spring"), "ab", 'A').unwrap();
+    /// assert_eq!(
+    ///     "  SPRINGABCDEFHJKLMOQTUVWXYZ\n\
+    ///      A UVWXYZABCDEFGHIJKLMNOPQRST\n\
+    ///      B VWXYZABCDEFGHIJKLMNOPQRSTU",
+    ///     q.tableau()
+    /// );
+    /// ```
+    ///
+    pub fn tableau(&self) -> String {
+        let mut lines = vec![format!("  {}", self.plain.iter().collect::<String>())];
+        for (k, cipher) in &self.rows {
+            lines.push(format!("{} {}", k, cipher.iter().collect::<String>()));
+        }
+        lines.join("\n")
+    }
+
+    // Finds the position of a letter in an alphabet, ignoring case.
+    fn index(alphabet: &[char], c: char) -> usize {
+        let c = c.to_ascii_uppercase();
+        alphabet.iter().position(|&a| a == c).unwrap()
+    }
+
+    // Gives an uppercase letter the case of the original character.
+    fn with_case(c: char, original: char) -> char {
+        if original.is_ascii_lowercase() {
+            c.to_ascii_lowercase()
+        } else {
+            c
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Quagmire, Variant};
+
+    #[test]
+    fn valid_keys() {
+        assert!(Quagmire::new(Variant::Four("senator", "percy"), "extra", 'S').is_ok());
+    }
+
+    #[test]
+    fn invalid_keyword() {
+        assert!(Quagmire::new(Variant::Two("sen4tor"), "extra", 'A').is_err());
+    }
+
+    #[test]
+    fn empty_indicator() {
+        assert!(Quagmire::new(Variant::One("senator"), "", 'A').is_err());
+    }
+
+    #[test]
+    fn invalid_alignment() {
+        assert!(Quagmire::new(Variant::One("senator"), "extra", '4').is_err());
+    }
+
+    #[test]
+    fn encipher_kryptos() {
+        let q = Quagmire::new(Variant::Three("kryptos"), "palimpsest", 'K').unwrap();
+        assert_eq!(
+            "EMUFPHZLRFAXYUSDJKZLDKRNSHGNFIVJYQTQUXQBQVYUVLLTREVJYQTMKYRDMFD",
+            q.encipher("BETWEENSUBTLESHADINGANDTHEABSENCEOFLIGHTLIESTHENUANCEOFIQLUSION")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn decipher_kryptos() {
+        let q = Quagmire::new(Variant::Three("kryptos"), "palimpsest", 'K').unwrap();
+        assert_eq!(
+            "BETWEENSUBTLESHADINGANDTHEABSENCEOFLIGHTLIESTHENUANCEOFIQLUSION",
+            q.decipher("EMUFPHZLRFAXYUSDJKZLDKRNSHGNFIVJYQTQUXQBQVYUVLLTREVJYQTMKYRDMFD")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn encipher_type_one() {
+        let q = Quagmire::new(Variant::One("spring"), "flower", 'A').unwrap();
+        assert_eq!("Lwb zejz!", q.encipher("Hot days!").unwrap());
+    }
+
+    #[test]
+    fn encipher_type_two() {
+        let q = Quagmire::new(Variant::Two("spring"), "flower", 'A').unwrap();
+        assert_eq!("Qie zesi!", q.encipher("Hot days!").unwrap());
+    }
+
+    #[test]
+    fn round_trip_type_four() {
+        let q = Quagmire::new(Variant::Four("senator", "percy"), "extra", 'S').unwrap();
+        let plaintext = "Meet me by the old mill at midnight";
+        assert_eq!(
+            plaintext,
+            q.decipher(&q.encipher(plaintext).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn tableau() {
+        let q = Quagmire::new(Variant::Two("spring"), "fl", 'A').unwrap();
+        assert_eq!(
+            "  ABCDEFGHIJKLMNOPQRSTUVWXYZ\n\
+             F FHJKLMOQTUVWXYZSPRINGABCDE\n\
+             L LMOQTUVWXYZSPRINGABCDEFHJK",
+            q.tableau()
+        );
+    }
+}
@@ -1,9 +1,13 @@
+use common::column_order;
+
 /// Rail Fence Cipher
 ///
-/// The struct is generated through the new() function.
+/// The struct is generated through the new() or with_offset() functions.
 ///
 pub struct RailFence {
     key: usize,
+    offset: usize,
+    rails: Vec<usize>,
 }
 
 impl RailFence {
@@ -22,10 +26,38 @@ impl RailFence {
     /// Will return Err() if the key is zero.
     ///
     pub fn new(key: usize) -> Result<Self, String> {
+        RailFence::with_offset(key, 0)
+    }
+
+    /// Initializes a rail fence cipher with a supplied height, starting the zigzag
+    /// a number of positions into its cycle rather than on the top rail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::railfence::RailFence;
+    ///
+    /// let r = RailFence::with_offset(3, 1).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key is zero or the offset is not less than the
+    /// length of a zigzag cycle.
+    ///
+    pub fn with_offset(key: usize, offset: usize) -> Result<Self, String> {
         if key == 0 {
             Err(String::from("The key must be 1 or greater"))
+        } else if offset > 0 && offset >= 2 * key - 2 {
+            Err(String::from(
+                "The offset must be less than the zigzag cycle",
+            ))
         } else {
-            Ok(RailFence { key })
+            Ok(RailFence {
+                key,
+                offset,
+                rails: (0..key).collect(),
+            })
         }
     }
 
@@ -89,9 +121,46 @@ impl RailFence {
         Ok(plaintext.iter().collect::<String>())
     }
 
+    /// Lays a message out on the zigzag, with a row for each rail and a column for
+    /// each character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::railfence::RailFence;
+    ///
+    /// let r = RailFence::new(3).unwrap();
+    /// let rendered = r
+    ///     .grid("WEAREDISCOVERED")
+    ///     .iter()
+    ///     .map(|rail| rail.iter().map(|c| c.unwrap_or('.')).collect::<String>())
+    ///     .collect::<Vec<String>>();
+    /// assert_eq!(
+    ///     vec!["W...E...C...R..", ".E.R.D.S.O.E.E.", "..A...I...V...D"],
+    ///     rendered
+    /// );
+    /// ```
+    ///
+    pub fn grid(&self, text: &str) -> Vec<Vec<Option<char>>> {
+        let length = text.chars().count();
+        let mut matrix = vec![vec![None; length]; self.key];
+
+        for (p, c) in text.chars().enumerate() {
+            let row = if self.key == 1 {
+                0
+            } else {
+                self.calculate_row(p).unwrap()
+            };
+            matrix[row][p] = Some(c);
+        }
+
+        matrix
+    }
+
     // Calculate the row a given position is in matrix.
     fn calculate_row(&self, position: usize) -> Result<usize, String> {
         let iteration = 2 * self.key - 2;
+        let position = position + self.offset;
 
         if position % iteration <= iteration / 2 {
             Ok(position % iteration)
@@ -102,19 +171,12 @@ impl RailFence {
 
     // Calculate the order in which the text will be arranged.
     fn calculate_order(&self, text: &str) -> Result<Vec<usize>, String> {
-        let length = text.chars().count();
-        let mut matrix = vec![vec![(' ', false); length]; self.key];
-
-        for (p, c) in text.chars().enumerate() {
-            let row = self.calculate_row(p).unwrap();
-            matrix[row][p] = (c, true);
-        }
-        let matrix = matrix;
+        let matrix = self.grid(text);
 
         let mut order = Vec::new();
-        for row in &matrix {
-            for (p, c) in row.iter().enumerate() {
-                if c.1 {
+        for &rail in &self.rails {
+            for (p, c) in matrix[rail].iter().enumerate() {
+                if c.is_some() {
                     order.push(p);
                 }
             }
@@ -124,9 +186,84 @@ impl RailFence {
     }
 }
 
+/// Redefence Cipher
+///
+/// A rail fence cipher where the rails are read out in a keyed order.
+///
+/// The struct is generated through the new() function.
+///
+pub struct Redefence {
+    fence: RailFence,
+}
+
+impl Redefence {
+    /// Initializes a redefence cipher with a key and an offset. There is a rail for
+    /// each character of the key and the rails are read out in the alphabetical
+    /// order of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::railfence::Redefence;
+    ///
+    /// let r = Redefence::new("312", 0).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key is empty or not alphanumeric, or the offset is
+    /// not less than the length of a zigzag cycle.
+    ///
+    pub fn new(key: &str, offset: usize) -> Result<Self, String> {
+        if !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(String::from("Key must be alphanumeric"));
+        }
+        let mut fence = RailFence::with_offset(key.chars().count(), offset)?;
+        fence.rails = column_order(key);
+        Ok(Redefence { fence })
+    }
+
+    /// Enciphers a message with a redefence cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::railfence::Redefence;
+    ///
+    /// let r = Redefence::new("312", 0).unwrap();
+    /// assert_eq!("ERDSOEEAIVDWECR", r.encipher("WEAREDISCOVERED").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        self.fence.encipher(plaintext)
+    }
+
+    /// Deciphers a message with a redefence cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::railfence::Redefence;
+    ///
+    /// let r = Redefence::new("312", 0).unwrap();
+    /// assert_eq!("WEAREDISCOVERED", r.decipher("ERDSOEEAIVDWECR").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        self.fence.decipher(ciphertext)
+    }
+
+    /// Lays a message out on the zigzag, with a row for each rail and a column for
+    /// each character.
+    ///
+    pub fn grid(&self, text: &str) -> Vec<Vec<Option<char>>> {
+        self.fence.grid(text)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::RailFence;
+    use super::{RailFence, Redefence};
 
     #[test]
     fn valid_height() {
@@ -177,6 +314,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invalid_offset() {
+        assert!(RailFence::with_offset(3, 4).is_err());
+    }
+
+    #[test]
+    fn encipher_with_offset() {
+        let r = RailFence::with_offset(3, 1).unwrap();
+        assert_eq!("RSEWAEICVRDEDOE", r.encipher("WEAREDISCOVERED").unwrap());
+    }
+
+    #[test]
+    fn decipher_with_offset() {
+        let r = RailFence::with_offset(3, 1).unwrap();
+        assert_eq!("WEAREDISCOVERED", r.decipher("RSEWAEICVRDEDOE").unwrap());
+    }
+
+    #[test]
+    fn zigzag_grid() {
+        let r = RailFence::with_offset(3, 1).unwrap();
+        let grid = r.grid("WEARE");
+        assert_eq!(vec![None, None, None, Some('R'), None], grid[0]);
+        assert_eq!(vec![Some('W'), None, Some('A'), None, Some('E')], grid[1]);
+        assert_eq!(vec![None, Some('E'), None, None, None], grid[2]);
+    }
+
+    #[test]
+    fn redefence_invalid_key() {
+        assert!(Redefence::new("3-1-2", 0).is_err());
+    }
+
+    #[test]
+    fn redefence_encipher() {
+        let r = Redefence::new("312", 0).unwrap();
+        assert_eq!("ERDSOEEAIVDWECR", r.encipher("WEAREDISCOVERED").unwrap());
+    }
+
+    #[test]
+    fn redefence_decipher() {
+        let r = Redefence::new("312", 0).unwrap();
+        assert_eq!("WEAREDISCOVERED", r.decipher("ERDSOEEAIVDWECR").unwrap());
+    }
+
+    #[test]
+    fn redefence_with_offset() {
+        let r = Redefence::new("2413", 3).unwrap();
+        let plaintext = "We are discovered, flee at once";
+        assert_eq!(
+            plaintext,
+            r.decipher(&r.encipher(plaintext).unwrap()).unwrap()
+        );
+    }
+
     #[test]
     fn decipher_with_key_of_one() {
         let r = RailFence::new(1).unwrap();
@@ -54,18 +54,206 @@ impl Rot13 {
     fn shift(text: &str, rot: u8) -> Result<String, &'static str> {
         Ok(text
             .chars()
-            .map(|c| match c as u8 {
-                65..=90 => (((c as u8 - 65 + rot) % 26) + 65) as char,
-                97..=122 => (((c as u8 - 97 + rot) % 26) + 97) as char,
+            .map(|c| match c {
+                'A'..='Z' => (((c as u8 - 65 + rot) % 26) + 65) as char,
+                'a'..='z' => (((c as u8 - 97 + rot) % 26) + 97) as char,
                 _ => c,
             })
             .collect::<String>())
     }
 }
 
+/// ROT5 Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Rot5 {}
+
+impl Rot5 {
+    /// Initializes a rot5 cipher, which rotates digits by five.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rot13::Rot5;
+    ///
+    /// let c = Rot5::new().unwrap();
+    /// ```
+    ///
+    pub fn new() -> Result<Self, String> {
+        Ok(Rot5 {})
+    }
+
+    /// Enciphers a message with a rot5 cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rot13::Rot5;
+    ///
+    /// let c = Rot5::new().unwrap();
+    /// assert_eq!("Call 000-9876", c.encipher("Call 555-4321").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, &'static str> {
+        Ok(rotate_digits(plaintext))
+    }
+
+    /// Deciphers a message with a rot5 cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rot13::Rot5;
+    ///
+    /// let c = Rot5::new().unwrap();
+    /// assert_eq!("Call 555-4321", c.decipher("Call 000-9876").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, &'static str> {
+        Ok(rotate_digits(ciphertext))
+    }
+}
+
+/// ROT18 Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Rot18 {}
+
+impl Rot18 {
+    /// Initializes a rot18 cipher, which rotates letters by thirteen and digits by
+    /// five.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rot13::Rot18;
+    ///
+    /// let c = Rot18::new().unwrap();
+    /// ```
+    ///
+    pub fn new() -> Result<Self, String> {
+        Ok(Rot18 {})
+    }
+
+    /// Enciphers a message with a rot18 cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rot13::Rot18;
+    ///
+    /// let c = Rot18::new().unwrap();
+    /// assert_eq!("zrrg ng 6 cz", c.encipher("meet at 1 pm").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, &'static str> {
+        Rot13::shift(&rotate_digits(plaintext), 13)
+    }
+
+    /// Deciphers a message with a rot18 cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rot13::Rot18;
+    ///
+    /// let c = Rot18::new().unwrap();
+    /// assert_eq!("meet at 1 pm", c.decipher("zrrg ng 6 cz").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, &'static str> {
+        Rot13::shift(&rotate_digits(ciphertext), 13)
+    }
+}
+
+/// ROT47 Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Rot47 {}
+
+impl Rot47 {
+    /// Initializes a rot47 cipher, which rotates the printable ASCII characters
+    /// from ! to ~ by forty seven.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rot13::Rot47;
+    ///
+    /// let c = Rot47::new().unwrap();
+    /// ```
+    ///
+    pub fn new() -> Result<Self, String> {
+        Ok(Rot47 {})
+    }
+
+    /// Enciphers a message with a rot47 cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rot13::Rot47;
+    ///
+    /// let c = Rot47::new().unwrap();
+    /// assert_eq!("w6==@[ (@C=5P", c.encipher("Hello, World!").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, &'static str> {
+        Ok(Rot47::rotate(plaintext))
+    }
+
+    /// Deciphers a message with a rot47 cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rot13::Rot47;
+    ///
+    /// let c = Rot47::new().unwrap();
+    /// assert_eq!("Hello, World!", c.decipher("w6==@[ (@C=5P").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, &'static str> {
+        Ok(Rot47::rotate(ciphertext))
+    }
+
+    fn rotate(text: &str) -> String {
+        text.chars()
+            .map(|c| match c as u32 {
+                33..=126 => (((c as u8 - 33 + 47) % 94) + 33) as char,
+                _ => c,
+            })
+            .collect()
+    }
+}
+
+// Rotates the digits in a message by five, leaving other characters alone.
+fn rotate_digits(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '0'..='9' => (((c as u8 - 48 + 5) % 10) + 48) as char,
+            _ => c,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Rot13;
+    use super::{Rot13, Rot18, Rot47, Rot5};
+
+    // Checks that enciphering and deciphering agree, and that enciphering twice
+    // gives back the text.
+    fn assert_involution<E, D>(encipher: E, decipher: D, text: &str)
+    where
+        E: Fn(&str) -> Result<String, &'static str>,
+        D: Fn(&str) -> Result<String, &'static str>,
+    {
+        assert_eq!(decipher(text).unwrap(), encipher(text).unwrap());
+        assert_eq!(text, encipher(&encipher(text).unwrap()).unwrap());
+    }
 
     #[test]
     fn encipher() {
@@ -96,4 +284,75 @@ mod tests {
             c.encipher("I 🖤 cryptography").unwrap()
         );
     }
+
+    #[test]
+    fn rot13_is_an_involution() {
+        let c = Rot13::new().unwrap();
+        assert_involution(
+            |t| c.encipher(t),
+            |t| c.decipher(t),
+            "Łódź: the quick brown fox jumps over 13 lazy dogs!",
+        );
+    }
+
+    #[test]
+    fn rot18_non_ascii_letters() {
+        let c = Rot18::new().unwrap();
+        assert_eq!("Łóqź 6", c.encipher("Łódź 1").unwrap());
+    }
+
+    #[test]
+    fn rot5_encipher() {
+        let c = Rot5::new().unwrap();
+        assert_eq!("5678901234 abc", c.encipher("0123456789 abc").unwrap());
+    }
+
+    #[test]
+    fn rot5_is_an_involution() {
+        let c = Rot5::new().unwrap();
+        assert_involution(
+            |t| c.encipher(t),
+            |t| c.decipher(t),
+            "Launch at 0400, 17 June 1944",
+        );
+    }
+
+    #[test]
+    fn rot18_encipher() {
+        let c = Rot18::new().unwrap();
+        assert_eq!(
+            "Ynhapu ng 5955, 62 Whar 6499",
+            c.encipher("Launch at 0400, 17 June 1944").unwrap()
+        );
+    }
+
+    #[test]
+    fn rot18_is_an_involution() {
+        let c = Rot18::new().unwrap();
+        assert_involution(
+            |t| c.encipher(t),
+            |t| c.decipher(t),
+            "Łukasiewicz 🖤 cryptography since 1976",
+        );
+    }
+
+    #[test]
+    fn rot47_encipher() {
+        let c = Rot47::new().unwrap();
+        assert_eq!(
+            r#"%96 "F:4< qC@H? 7@I ;F>AD @G6C `b =2KJ 5@8D O"#,
+            c.encipher("The Quick Brown fox jumps over 13 lazy dogs ~")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn rot47_is_an_involution() {
+        let c = Rot47::new().unwrap();
+        assert_involution(
+            |t| c.encipher(t),
+            |t| c.decipher(t),
+            "I 🖤 cryptography! {~} 1976",
+        );
+    }
 }
@@ -0,0 +1,300 @@
+/// The wiring and settings of a rotor in a rotor machine.
+///
+/// The wiring lists where each contact of the alphabet is wired to, and the
+/// notches list the positions at which the rotor carries the next rotor along.
+/// The ring setting turns the wiring against the alphabet ring and the position
+/// is the letter showing in the window. A reversed rotor has its wiring core
+/// turned over inside the ring.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RotorSpec<'a> {
+    pub wiring: &'a str,
+    pub notches: &'a str,
+    pub ring: char,
+    pub position: char,
+    pub reversed: bool,
+}
+
+impl<'a> RotorSpec<'a> {
+    /// Describes a rotor at the ring setting and position of its first contact,
+    /// which is A for a lettered rotor and 0 for a numbered one, the usual
+    /// starting point before a key is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::rotor::RotorSpec;
+    ///
+    /// let r = RotorSpec::new("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "Q");
+    /// assert_eq!('A', r.position);
+    /// ```
+    ///
+    pub fn new(wiring: &'a str, notches: &'a str) -> Self {
+        let first = wiring.chars().min().unwrap_or('A');
+        RotorSpec {
+            wiring,
+            notches,
+            ring: first,
+            position: first,
+            reversed: false,
+        }
+    }
+}
+
+// A rotor set up from its spec, turning contacts into indices of its alphabet.
+#[derive(Clone)]
+pub(crate) struct Rotor {
+    forward: Vec<usize>,
+    backward: Vec<usize>,
+    notches: Vec<usize>,
+    ring: usize,
+    position: usize,
+}
+
+impl Rotor {
+    // Sets up a rotor over an alphabet, checking the spec against it.
+    pub(crate) fn new(spec: &RotorSpec, alphabet: &str) -> Result<Rotor, String> {
+        let alphabet = alphabet.chars().collect::<Vec<char>>();
+        let index = |c: char| alphabet.iter().position(|&a| a == c.to_ascii_uppercase());
+
+        let mut forward = spec
+            .wiring
+            .chars()
+            .map(|c| index(c).ok_or_else(|| format!("Rotor wiring has '{}'", c)))
+            .collect::<Result<Vec<usize>, String>>()?;
+        if forward.len() != alphabet.len() || (0..alphabet.len()).any(|i| !forward.contains(&i)) {
+            return Err(String::from("Rotor wiring must use each contact once"));
+        }
+        let n = forward.len();
+        if spec.reversed {
+            let flipped = forward.clone();
+            for (i, &o) in flipped.iter().enumerate() {
+                forward[(n - o) % n] = (n - i) % n;
+            }
+        }
+        let mut backward = vec![0; n];
+        for (i, &o) in forward.iter().enumerate() {
+            backward[o] = i;
+        }
+
+        let notches = spec
+            .notches
+            .chars()
+            .map(|c| index(c).ok_or_else(|| format!("Rotor notch '{}' is not on the ring", c)))
+            .collect::<Result<Vec<usize>, String>>()?;
+        let ring = index(spec.ring).ok_or("Ring setting is not on the ring")?;
+        let position = index(spec.position).ok_or("Rotor position is not on the ring")?;
+
+        Ok(Rotor {
+            forward,
+            backward,
+            notches,
+            ring,
+            position,
+        })
+    }
+
+    // Passes a contact through the rotor from the entry side.
+    pub(crate) fn forward(&self, contact: usize) -> usize {
+        self.pass(contact, &self.forward)
+    }
+
+    // Passes a contact back through the rotor from the far side.
+    pub(crate) fn backward(&self, contact: usize) -> usize {
+        self.pass(contact, &self.backward)
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    // Whether the rotor is at a notch, ready to carry the next rotor.
+    pub(crate) fn at_notch(&self) -> bool {
+        self.notches.contains(&self.position)
+    }
+
+    pub(crate) fn step(&mut self) {
+        self.position = (self.position + 1) % self.forward.len();
+    }
+
+    fn pass(&self, contact: usize, wiring: &[usize]) -> usize {
+        let n = wiring.len();
+        let shift = (self.position + n - self.ring) % n;
+        (wiring[(contact + shift) % n] + n - shift) % n
+    }
+}
+
+// A fixed pairing of contacts, as made by a plugboard or a reflector.
+#[derive(Clone)]
+pub(crate) struct Pairs {
+    wiring: Vec<usize>,
+}
+
+impl Pairs {
+    // Pairs the letters of each two letter group, such as "AB CD", leaving the
+    // other letters unpaired.
+    pub(crate) fn new(pairs: &str) -> Result<Pairs, String> {
+        let mut wiring = (0..26).collect::<Vec<usize>>();
+        for pair in pairs.split_whitespace() {
+            let pair = pair.to_ascii_uppercase().into_bytes();
+            if pair.len() != 2 || !pair.iter().all(|b| b.is_ascii_uppercase()) {
+                return Err(String::from("Pairs must be two letters"));
+            }
+            let (a, b) = ((pair[0] - 65) as usize, (pair[1] - 65) as usize);
+            if a == b || wiring[a] != a || wiring[b] != b {
+                return Err(String::from("Each letter can only be paired once"));
+            }
+            wiring[a] = b;
+            wiring[b] = a;
+        }
+        Ok(Pairs { wiring })
+    }
+
+    // Pairs the letters of a reflector, written as the letter each is wired to.
+    pub(crate) fn reflector(wiring: &str) -> Result<Pairs, String> {
+        let wiring = wiring.to_ascii_uppercase().into_bytes();
+        if wiring.len() != 26 || !wiring.iter().all(|b| b.is_ascii_uppercase()) {
+            return Err(String::from("Reflector must be 26 letters"));
+        }
+        let wiring = wiring.iter().map(|b| (b - 65) as usize).collect::<Vec<_>>();
+        if wiring
+            .iter()
+            .enumerate()
+            .any(|(i, &o)| o == i || wiring[o] != i)
+        {
+            return Err(String::from(
+                "Reflector must pair every letter with another",
+            ));
+        }
+        Ok(Pairs { wiring })
+    }
+
+    pub(crate) fn swap(&self, contact: usize) -> usize {
+        self.wiring[contact]
+    }
+
+    // The letter each letter is paired with, written as a reflector wiring.
+    pub(crate) fn wiring(&self) -> String {
+        self.wiring
+            .iter()
+            .map(|&c| (c as u8 + 65) as char)
+            .collect()
+    }
+}
+
+// Steps a bank of rotors, ordered from the slowest to the fastest, with pawls
+// like the Enigma. The fastest rotor always steps, and each pawl that drops into
+// a notch steps both the rotor it sits on and the rotor to the left, which gives
+// the double step of the middle rotors.
+pub(crate) fn step_with_pawls(rotors: &mut [Rotor]) {
+    let last = rotors.len() - 1;
+    let steps = (0..rotors.len())
+        .map(|i| i == last || rotors[i + 1].at_notch() || (i > 0 && rotors[i].at_notch()))
+        .collect::<Vec<bool>>();
+    for (rotor, step) in rotors.iter_mut().zip(steps) {
+        if step {
+            rotor.step();
+        }
+    }
+}
+
+// Steps a bank of rotors, ordered from the slowest to the fastest, with gears
+// like a counter. The fastest rotor always steps and each rotor that moves on
+// from a notch carries the rotor to its left, so there is no double step.
+pub(crate) fn step_with_gears(rotors: &mut [Rotor]) {
+    for rotor in rotors.iter_mut().rev() {
+        let carry = rotor.at_notch();
+        rotor.step();
+        if !carry {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{step_with_gears, step_with_pawls, Pairs, Rotor, RotorSpec};
+    use common::ALPHABET;
+
+    fn rotor(wiring: &str, notches: &str, position: char) -> Rotor {
+        let mut spec = RotorSpec::new(wiring, notches);
+        spec.position = position;
+        Rotor::new(&spec, ALPHABET).unwrap()
+    }
+
+    #[test]
+    fn invalid_wiring() {
+        let spec = RotorSpec::new("EKMFLGDQVZNTOWYHXUSPAIBRCE", "Q");
+        assert!(Rotor::new(&spec, ALPHABET).is_err());
+    }
+
+    #[test]
+    fn ring_setting() {
+        let mut spec = RotorSpec::new("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "Q");
+        spec.ring = 'B';
+        let r = Rotor::new(&spec, ALPHABET).unwrap();
+        assert_eq!(10, r.forward(0));
+    }
+
+    #[test]
+    fn backward_inverts_forward() {
+        let r = rotor("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "Q", 'M');
+        for i in 0..26 {
+            assert_eq!(i, r.backward(r.forward(i)));
+        }
+    }
+
+    #[test]
+    fn reversed_rotor() {
+        let mut spec = RotorSpec::new("BACDEFGHIJKLMNOPQRSTUVWXYZ", "");
+        spec.reversed = true;
+        let r = Rotor::new(&spec, ALPHABET).unwrap();
+        assert_eq!(25, r.forward(0));
+    }
+
+    #[test]
+    fn double_step() {
+        let mut rotors = vec![
+            rotor("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "Q", 'A'),
+            rotor("AJDKSIRUXBLHWTMCQGZNPYFVOE", "E", 'D'),
+            rotor("BDFHJLCPRTXVZNYEIWGAKMUSQO", "V", 'U'),
+        ];
+        let mut positions = Vec::new();
+        for _ in 0..3 {
+            step_with_pawls(&mut rotors);
+            positions.push(rotors.iter().map(|r| r.position()).collect::<Vec<_>>());
+        }
+        assert_eq!(
+            vec![vec![0, 3, 21], vec![0, 4, 22], vec![1, 5, 23]],
+            positions
+        );
+    }
+
+    #[test]
+    fn gears_do_not_double_step() {
+        let mut rotors = vec![
+            rotor("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "Q", 'A'),
+            rotor("AJDKSIRUXBLHWTMCQGZNPYFVOE", "E", 'D'),
+            rotor("BDFHJLCPRTXVZNYEIWGAKMUSQO", "V", 'U'),
+        ];
+        let mut positions = Vec::new();
+        for _ in 0..3 {
+            step_with_gears(&mut rotors);
+            positions.push(rotors.iter().map(|r| r.position()).collect::<Vec<_>>());
+        }
+        assert_eq!(
+            vec![vec![0, 3, 21], vec![0, 4, 22], vec![0, 4, 23]],
+            positions
+        );
+    }
+
+    #[test]
+    fn invalid_pairs() {
+        assert!(Pairs::new("AB BC").is_err());
+    }
+
+    #[test]
+    fn invalid_reflector() {
+        assert!(Pairs::reflector("ABCDEFGHIJKLMNOPQRSTUVWXYZ").is_err());
+    }
+}
@@ -0,0 +1,276 @@
+use common::grid::Grid;
+
+/// The route a message is read off the grid along.
+///
+/// Spirals start or end in the top left corner. Diagonals are read from the top
+/// left corner, each running down and to the left. Boustrophedon reads the rows
+/// alternately left to right and right to left.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Path {
+    SpiralInClockwise,
+    SpiralInCounterClockwise,
+    SpiralOutClockwise,
+    SpiralOutCounterClockwise,
+    Boustrophedon,
+    Diagonal,
+    ColumnsDown,
+    ColumnsUp,
+}
+
+/// Route Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Route {
+    rows: usize,
+    cols: usize,
+    path: Path,
+}
+
+impl Route {
+    /// Initializes a route cipher with the dimensions of the grid and a route.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::route::{Path, Route};
+    ///
+    /// let r = Route::new(3, 4, Path::SpiralInClockwise).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if either dimension is zero.
+    ///
+    pub fn new(rows: usize, cols: usize, path: Path) -> Result<Self, String> {
+        if rows == 0 || cols == 0 {
+            Err(String::from("The grid must have at least 1 row and column"))
+        } else {
+            Ok(Route { rows, cols, path })
+        }
+    }
+
+    /// Enciphers a message with a route cipher.
+    ///
+    /// The message is written into the grid by rows. Cells after the end of a
+    /// short message are left empty and skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::route::{Path, Route};
+    ///
+    /// let r = Route::new(3, 4, Path::SpiralInClockwise).unwrap();
+    /// assert_eq!("ABCDHLKJIEFG", r.encipher("ABCDEFGHIJKL").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the message does not fit in the grid.
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let text = plaintext.chars().collect::<Vec<char>>();
+        Ok(Grid::transpose(&text, &self.order(text.len())?))
+    }
+
+    /// Deciphers a message with a route cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::route::{Path, Route};
+    ///
+    /// let r = Route::new(3, 4, Path::SpiralInClockwise).unwrap();
+    /// assert_eq!("ABCDEFGHIJKL", r.decipher("ABCDHLKJIEFG").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the message does not fit in the grid.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let text = ciphertext.chars().collect::<Vec<char>>();
+        Ok(Grid::restore(&text, &self.order(text.len())?))
+    }
+
+    // Calculates the order the characters of the text are read out in.
+    fn order(&self, length: usize) -> Result<Vec<usize>, String> {
+        if length > self.rows * self.cols {
+            return Err(String::from("The text is too long for the grid"));
+        }
+
+        let (rows, cols) = (self.rows, self.cols);
+        let positions = match self.path {
+            Path::SpiralInClockwise => Route::spiral(rows, cols),
+            Path::SpiralInCounterClockwise => Route::transposed(Route::spiral(cols, rows)),
+            Path::SpiralOutClockwise => {
+                let mut spiral = Route::transposed(Route::spiral(cols, rows));
+                spiral.reverse();
+                spiral
+            }
+            Path::SpiralOutCounterClockwise => {
+                let mut spiral = Route::spiral(rows, cols);
+                spiral.reverse();
+                spiral
+            }
+            Path::Boustrophedon => (0..rows)
+                .flat_map(|r| {
+                    (0..cols).map(move |c| {
+                        if r % 2 == 0 {
+                            (r, c)
+                        } else {
+                            (r, cols - 1 - c)
+                        }
+                    })
+                })
+                .collect(),
+            Path::Diagonal => (0..rows + cols - 1)
+                .flat_map(|d| {
+                    (0..rows)
+                        .filter(move |&r| r <= d && d - r < cols)
+                        .map(move |r| (r, d - r))
+                })
+                .collect(),
+            Path::ColumnsDown => (0..cols)
+                .flat_map(|c| (0..rows).map(move |r| (r, c)))
+                .collect(),
+            Path::ColumnsUp => (0..cols)
+                .flat_map(|c| (0..rows).rev().map(move |r| (r, c)))
+                .collect(),
+        };
+
+        Ok(Grid::new(self.cols, length).read(&positions))
+    }
+
+    // The positions of a clockwise spiral from the top left corner to the centre.
+    fn spiral(rows: usize, cols: usize) -> Vec<(usize, usize)> {
+        let mut positions = Vec::new();
+        let (mut top, mut left) = (0, 0);
+        let (mut bottom, mut right) = (rows as isize - 1, cols as isize - 1);
+
+        while top <= bottom && left <= right {
+            for c in left..=right {
+                positions.push((top, c));
+            }
+            for r in top + 1..=bottom {
+                positions.push((r, right));
+            }
+            if top < bottom {
+                for c in (left..right).rev() {
+                    positions.push((bottom, c));
+                }
+            }
+            if left < right {
+                for r in (top + 1..bottom).rev() {
+                    positions.push((r, left));
+                }
+            }
+            top += 1;
+            left += 1;
+            bottom -= 1;
+            right -= 1;
+        }
+
+        positions
+            .iter()
+            .map(|&(r, c)| (r as usize, c as usize))
+            .collect()
+    }
+
+    // Swaps the row and column of each position.
+    fn transposed(positions: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+        positions.iter().map(|&(r, c)| (c, r)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Path, Route};
+
+    const TEXT: &str = "ABCDEFGHIJKL";
+
+    fn encipher(path: Path) -> String {
+        Route::new(3, 4, path).unwrap().encipher(TEXT).unwrap()
+    }
+
+    #[test]
+    fn invalid_dimensions() {
+        assert!(Route::new(0, 4, Path::Diagonal).is_err());
+    }
+
+    #[test]
+    fn text_too_long() {
+        let r = Route::new(2, 2, Path::Diagonal).unwrap();
+        assert!(r.encipher("ABCDE").is_err());
+    }
+
+    #[test]
+    fn spiral_in_clockwise() {
+        assert_eq!("ABCDHLKJIEFG", encipher(Path::SpiralInClockwise));
+    }
+
+    #[test]
+    fn spiral_in_counter_clockwise() {
+        assert_eq!("AEIJKLHDCBFG", encipher(Path::SpiralInCounterClockwise));
+    }
+
+    #[test]
+    fn spiral_out_clockwise() {
+        assert_eq!("GFBCDHLKJIEA", encipher(Path::SpiralOutClockwise));
+    }
+
+    #[test]
+    fn spiral_out_counter_clockwise() {
+        assert_eq!("GFEIJKLHDCBA", encipher(Path::SpiralOutCounterClockwise));
+    }
+
+    #[test]
+    fn boustrophedon() {
+        assert_eq!("ABCDHGFEIJKL", encipher(Path::Boustrophedon));
+    }
+
+    #[test]
+    fn diagonal() {
+        assert_eq!("ABECFIDGJHKL", encipher(Path::Diagonal));
+    }
+
+    #[test]
+    fn columns_down() {
+        assert_eq!("AEIBFJCGKDHL", encipher(Path::ColumnsDown));
+    }
+
+    #[test]
+    fn columns_up() {
+        assert_eq!("IEAJFBKGCLHD", encipher(Path::ColumnsUp));
+    }
+
+    #[test]
+    fn short_message() {
+        let r = Route::new(3, 4, Path::SpiralInClockwise).unwrap();
+        assert_eq!("ABCDHJIEFG", r.encipher("ABCDEFGHIJ").unwrap());
+        assert_eq!("ABCDEFGHIJ", r.decipher("ABCDHJIEFG").unwrap());
+    }
+
+    #[test]
+    fn all_paths_invert() {
+        let paths = [
+            Path::SpiralInClockwise,
+            Path::SpiralInCounterClockwise,
+            Path::SpiralOutClockwise,
+            Path::SpiralOutCounterClockwise,
+            Path::Boustrophedon,
+            Path::Diagonal,
+            Path::ColumnsDown,
+            Path::ColumnsUp,
+        ];
+        for &path in &paths {
+            let r = Route::new(5, 7, path).unwrap();
+            let plaintext = "We are discovered, flee at once";
+            assert_eq!(
+                plaintext,
+                r.decipher(&r.encipher(plaintext).unwrap()).unwrap()
+            );
+        }
+    }
+}
@@ -44,11 +44,7 @@ impl Scytale {
     /// Will return an error if the height is greater than the length of the text
     ///
     pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
-        let matrix = self.transpose(plaintext, false);
-        let matrix = match matrix {
-            Ok(val) => val,
-            Err(e) => return Err(e),
-        };
+        let matrix = self.transpose(plaintext, false)?;
 
         Ok(matrix
             .iter()
@@ -75,11 +71,7 @@ impl Scytale {
         let width = f64::ceil(ciphertext.chars().count() as f64 / self.height as f64) as usize;
 
         // Pass any errors from self.transpose()
-        let matrix = self.transpose(ciphertext, true);
-        let matrix = match matrix {
-            Ok(val) => val,
-            Err(e) => return Err(e),
-        };
+        let matrix = self.transpose(ciphertext, true)?;
 
         for row in 0..width {
             for col in matrix.iter().take(self.height) {
@@ -0,0 +1,317 @@
+use ciphers::rotor::{Rotor, RotorSpec};
+use common::ALPHABET;
+
+/// The contacts of the index rotors.
+pub const DIGITS: &str = "0123456789";
+
+// The contacts energized at the entry of the control rotors.
+const CONTROL_INPUTS: [usize; 4] = [5, 6, 7, 8];
+// The index rotor contact each control rotor output is wired to.
+const CONTROL_OUTPUTS: [usize; 26] = [
+    9, 1, 2, 3, 3, 4, 4, 4, 5, 5, 5, 6, 6, 6, 6, 7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8,
+];
+// The cipher rotor each index rotor output steps.
+const INDEX_OUTPUTS: [usize; 10] = [4, 0, 0, 1, 1, 2, 2, 3, 3, 4];
+// The position at which a control rotor carries the next one along.
+const CARRY: usize = 14;
+
+/// The setup of a SIGABA machine.
+///
+/// Each bank of five rotors is listed from left to right. The cipher and
+/// control rotors are wired over the alphabet and the index rotors over the
+/// digits 0 to 9. Notches are not used, as the control rotors step as an
+/// odometer and the index rotors stay where they are set.
+///
+/// No rotor tables are shipped with the crate, as the historical SIGABA cipher,
+/// control and index rotor wirings have not been checked here. See EXAMPLE for a
+/// setup to try the machine with.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SigabaConfig<'a> {
+    pub cipher: [RotorSpec<'a>; 5],
+    pub control: [RotorSpec<'a>; 5],
+    pub index: [RotorSpec<'a>; 5],
+}
+
+/// An example SIGABA setup with every rotor at its first contact.
+///
+/// The Enigma I to V rotor wirings stand in for the cipher rotors and the Enigma
+/// VI to VIII, beta and gamma wirings for the control rotors, so it checks the
+/// stepping and round trips rather than reproducing a real SIGABA message.
+///
+pub const EXAMPLE: SigabaConfig<'static> = SigabaConfig {
+    cipher: [
+        rotor("EKMFLGDQVZNTOWYHXUSPAIBRCJ", 'A'),
+        rotor("AJDKSIRUXBLHWTMCQGZNPYFVOE", 'A'),
+        rotor("BDFHJLCPRTXVZNYEIWGAKMUSQO", 'A'),
+        rotor("ESOVPZJAYQUIRHXLNFTGKDCMWB", 'A'),
+        rotor("VZBRGITYUPSDNHLXAWMJQOFECK", 'A'),
+    ],
+    control: [
+        rotor("JPGVOUMFYQBENHZRDKASXLICTW", 'A'),
+        rotor("NZJHGRCXMYSWBOUFAIVLPEKQDT", 'A'),
+        rotor("FKQHTLXOCBJSPDZRAMEWNIUYGV", 'A'),
+        rotor("LEYJVCNIXWPBQMDRTAKZGFUHOS", 'A'),
+        rotor("FSOKANUERHMBTPYCLXQGJZWVID", 'A'),
+    ],
+    index: [
+        rotor("7591482630", '0'),
+        rotor("3810592764", '0'),
+        rotor("4086153297", '0'),
+        rotor("3980526174", '0'),
+        rotor("6497135280", '0'),
+    ],
+};
+
+const fn rotor(wiring: &'static str, first: char) -> RotorSpec<'static> {
+    RotorSpec {
+        wiring,
+        notches: "",
+        ring: first,
+        position: first,
+        reversed: false,
+    }
+}
+
+/// SIGABA Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Sigaba {
+    cipher: Vec<Rotor>,
+    control: Vec<Rotor>,
+    index: Vec<Rotor>,
+}
+
+impl Sigaba {
+    /// Initializes a SIGABA machine from its configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::sigaba::{Sigaba, EXAMPLE};
+    ///
+    /// let s = Sigaba::new(&EXAMPLE).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if a rotor is not wired to each contact of its bank once,
+    /// or its ring setting or position is not one of those contacts.
+    ///
+    pub fn new(config: &SigabaConfig) -> Result<Self, String> {
+        let bank = |specs: &[RotorSpec], contacts: &str| {
+            specs
+                .iter()
+                .map(|spec| Rotor::new(spec, contacts))
+                .collect::<Result<Vec<Rotor>, String>>()
+        };
+
+        Ok(Sigaba {
+            cipher: bank(&config.cipher, ALPHABET)?,
+            control: bank(&config.control, ALPHABET)?,
+            index: bank(&config.index, DIGITS)?,
+        })
+    }
+
+    /// Enciphers a message with a SIGABA machine.
+    ///
+    /// The letter Z is enciphered as X and spaces as Z, other characters that are
+    /// not letters are removed, and the ciphertext is printed in groups of five.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::sigaba::{Sigaba, EXAMPLE};
+    ///
+    /// let s = Sigaba::new(&EXAMPLE).unwrap();
+    /// let ciphertext = s.encipher("Attack at dawn").unwrap();
+    /// assert_eq!("ATTACK AT DAWN", s.decipher(&ciphertext).unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let letters = plaintext
+            .chars()
+            .filter_map(|c| match c.to_ascii_uppercase() {
+                ' ' => Some('Z'),
+                'Z' => Some('X'),
+                c if c.is_ascii_uppercase() => Some(c),
+                _ => None,
+            })
+            .collect::<Vec<char>>();
+
+        let ciphertext = self.crypt(&letters, false);
+        Ok(ciphertext
+            .chunks(5)
+            .map(|group| group.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join(" "))
+    }
+
+    /// Deciphers a message with a SIGABA machine.
+    ///
+    /// Spaces in the ciphertext are ignored and the letter Z is printed as a space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::sigaba::{Sigaba, EXAMPLE};
+    ///
+    /// let s = Sigaba::new(&EXAMPLE).unwrap();
+    /// let ciphertext = s.encipher("Zero hour").unwrap();
+    /// assert_eq!("XERO HOUR", s.decipher(&ciphertext).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the ciphertext has characters other than letters and
+    /// spaces.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let mut letters = Vec::new();
+        for c in ciphertext.chars().filter(|&c| c != ' ') {
+            if !c.is_ascii_alphabetic() {
+                return Err(String::from("Ciphertext must be letters"));
+            }
+            letters.push(c.to_ascii_uppercase());
+        }
+
+        Ok(self
+            .crypt(&letters, true)
+            .iter()
+            .map(|&c| if c == 'Z' { ' ' } else { c })
+            .collect())
+    }
+
+    // Passes each letter through the cipher rotors, backwards to decipher, then
+    // steps the cipher rotors chosen by the control and index rotors.
+    fn crypt(&self, letters: &[char], reverse: bool) -> Vec<char> {
+        let mut cipher = self.cipher.clone();
+        let mut control = self.control.clone();
+        letters
+            .iter()
+            .map(|&c| {
+                let mut contact = c as usize - 65;
+                if reverse {
+                    for rotor in cipher.iter().rev() {
+                        contact = rotor.backward(contact);
+                    }
+                } else {
+                    for rotor in &cipher {
+                        contact = rotor.forward(contact);
+                    }
+                }
+
+                for (rotor, step) in cipher.iter_mut().zip(self.steps(&control)) {
+                    if step {
+                        rotor.step();
+                    }
+                }
+                Sigaba::step_control(&mut control);
+
+                (contact as u8 + 65) as char
+            })
+            .collect()
+    }
+
+    // Which cipher rotors step, found by passing the four live contacts through
+    // the control rotors and the index rotors.
+    fn steps(&self, control: &[Rotor]) -> Vec<bool> {
+        let mut steps = vec![false; 5];
+        for &input in &CONTROL_INPUTS {
+            let mut contact = input;
+            for rotor in control {
+                contact = rotor.forward(contact);
+            }
+            contact = CONTROL_OUTPUTS[contact];
+            for rotor in &self.index {
+                contact = rotor.forward(contact);
+            }
+            steps[INDEX_OUTPUTS[contact]] = true;
+        }
+        steps
+    }
+
+    // Steps the middle three control rotors as an odometer. The centre rotor is
+    // fast, the fourth is medium and the second is slow, and the outer two never
+    // move.
+    fn step_control(control: &mut [Rotor]) {
+        let medium = control[2].position() == CARRY;
+        let slow = medium && control[3].position() == CARRY;
+        control[2].step();
+        if medium {
+            control[3].step();
+        }
+        if slow {
+            control[1].step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sigaba, EXAMPLE};
+    use ciphers::rotor::RotorSpec;
+
+    #[test]
+    fn index_rotor_with_letters() {
+        let mut c = EXAMPLE;
+        c.index[2] = RotorSpec::new("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "");
+        assert!(Sigaba::new(&c).is_err());
+    }
+
+    #[test]
+    fn index_position_must_be_digit() {
+        let mut c = EXAMPLE;
+        c.index[0].position = 'A';
+        assert!(Sigaba::new(&c).is_err());
+    }
+
+    #[test]
+    fn one_to_four_cipher_rotors_step() {
+        let s = Sigaba::new(&EXAMPLE).unwrap();
+        let mut control = s.control.clone();
+        for _ in 0..1000 {
+            let count = s.steps(&control).iter().filter(|&&step| step).count();
+            assert!((1..=4).contains(&count));
+            Sigaba::step_control(&mut control);
+        }
+    }
+
+    #[test]
+    fn control_rotors_step_as_odometer() {
+        let s = Sigaba::new(&EXAMPLE).unwrap();
+        let mut control = s.control.clone();
+        for _ in 0..26 * 26 {
+            Sigaba::step_control(&mut control);
+        }
+        let positions = control.iter().map(|r| r.position()).collect::<Vec<_>>();
+        assert_eq!(vec![0, 1, 0, 0, 0], positions);
+    }
+
+    #[test]
+    fn spaces_and_z() {
+        let s = Sigaba::new(&EXAMPLE).unwrap();
+        let ciphertext = s.encipher("Zulu time, now").unwrap();
+        assert_eq!("XULU TIME NOW", s.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn round_trip_with_settings() {
+        let mut c = EXAMPLE;
+        c.cipher[1].reversed = true;
+        c.cipher[3].position = 'Q';
+        c.control[2].position = 'N';
+        c.index[4].position = '7';
+        let s = Sigaba::new(&c).unwrap();
+        let plaintext = "THE MACHINE WAS NEVER BROKEN DURING THE WAR";
+        let ciphertext = s.encipher(plaintext).unwrap();
+        assert_eq!(plaintext, s.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn invalid_ciphertext() {
+        let s = Sigaba::new(&EXAMPLE).unwrap();
+        assert!(s.decipher("ABC1").is_err());
+    }
+}
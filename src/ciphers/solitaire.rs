@@ -0,0 +1,346 @@
+use ciphers::vigenere::Vigenere;
+
+/// The value of the A joker in a deck.
+pub const JOKER_A: u8 = 53;
+/// The value of the B joker in a deck.
+pub const JOKER_B: u8 = 54;
+
+/// Solitaire Deck
+///
+/// A deck of 52 cards and two jokers that generates the solitaire keystream. The
+/// cards are numbered 1 through 52 in bridge order (clubs, diamonds, hearts,
+/// spades) and the jokers are `JOKER_A` and `JOKER_B`.
+///
+/// The struct is generated through the new(), ordered() or from_passphrase()
+/// functions.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deck {
+    cards: Vec<u8>,
+}
+
+impl Deck {
+    /// Initializes a deck from the order of its cards, top card first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::solitaire::Deck;
+    ///
+    /// let order = (1..55).rev().collect::<Vec<u8>>();
+    /// let d = Deck::new(&order).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the deck does not contain each card from 1 to 54 once.
+    ///
+    pub fn new(cards: &[u8]) -> Result<Self, String> {
+        if cards.len() != 54 {
+            return Err(String::from("Deck must have 54 cards"));
+        }
+        for card in 1..=JOKER_B {
+            if !cards.contains(&card) {
+                return Err(String::from("Deck must contain each card once"));
+            }
+        }
+        Ok(Deck {
+            cards: cards.to_vec(),
+        })
+    }
+
+    /// Initializes an unkeyed deck, ordered from 1 to 52 followed by the A and B
+    /// jokers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::solitaire::{Deck, JOKER_A, JOKER_B};
+    ///
+    /// let d = Deck::ordered();
+    /// assert_eq!(&[1, 2, 3], &d.cards()[..3]);
+    /// assert_eq!(&[JOKER_A, JOKER_B], &d.cards()[52..]);
+    /// ```
+    ///
+    pub fn ordered() -> Self {
+        Deck {
+            cards: (1..=JOKER_B).collect(),
+        }
+    }
+
+    /// Initializes a deck keyed by a passphrase.
+    ///
+    /// Starting from an ordered deck, each letter of the passphrase performs a
+    /// round of the algorithm without output followed by a second count cut of the
+    /// letter's value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::solitaire::Deck;
+    ///
+    /// let d = Deck::from_passphrase("cryptonomicon").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the passphrase is not alphabetic.
+    ///
+    pub fn from_passphrase(passphrase: &str) -> Result<Self, String> {
+        let mut deck = Deck::ordered();
+        for c in passphrase.chars() {
+            if !c.is_ascii_alphabetic() {
+                return Err(String::from("Passphrase must be alphabetic"));
+            }
+            deck.shuffle();
+            deck.count_cut(c.to_ascii_uppercase() as u8 - 64);
+        }
+        Ok(deck)
+    }
+
+    /// Returns the current order of the cards, top card first.
+    ///
+    pub fn cards(&self) -> &[u8] {
+        &self.cards
+    }
+
+    /// Performs one round of the algorithm and returns the output card, or None
+    /// if the output card is a joker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::solitaire::Deck;
+    ///
+    /// let mut d = Deck::ordered();
+    /// assert_eq!(Some(4), d.step());
+    /// assert_eq!(Some(49), d.step());
+    /// assert_eq!(Some(10), d.step());
+    /// assert_eq!(None, d.step());
+    /// ```
+    ///
+    pub fn step(&mut self) -> Option<u8> {
+        self.shuffle();
+
+        let output = self.cards[Deck::value(self.cards[0]) as usize];
+        if output >= JOKER_A {
+            None
+        } else {
+            Some(output)
+        }
+    }
+
+    /// Performs rounds of the algorithm until one outputs a card, and returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::solitaire::Deck;
+    ///
+    /// let mut d = Deck::ordered();
+    /// let keystream = (0..5).map(|_| d.next_card()).collect::<Vec<u8>>();
+    /// assert_eq!(vec![4, 49, 10, 24, 8], keystream);
+    /// ```
+    ///
+    pub fn next_card(&mut self) -> u8 {
+        loop {
+            if let Some(card) = self.step() {
+                return card;
+            }
+        }
+    }
+
+    // Moves the jokers, performs the triple cut and the count cut by the bottom
+    // card.
+    fn shuffle(&mut self) {
+        self.move_down(JOKER_A, 1);
+        self.move_down(JOKER_B, 2);
+        self.triple_cut();
+        let bottom = self.cards[53];
+        self.count_cut(bottom);
+    }
+
+    // Moves a card down the deck, wrapping past the bottom to below the top card.
+    fn move_down(&mut self, card: u8, count: usize) {
+        let index = self.cards.iter().position(|&c| c == card).unwrap();
+        self.cards.remove(index);
+        let mut index = index + count;
+        if index > 53 {
+            index -= 53;
+        }
+        self.cards.insert(index, card);
+    }
+
+    // Swaps the cards above the first joker with the cards below the second.
+    fn triple_cut(&mut self) {
+        let a = self.cards.iter().position(|&c| c == JOKER_A).unwrap();
+        let b = self.cards.iter().position(|&c| c == JOKER_B).unwrap();
+        let (first, second) = if a < b { (a, b) } else { (b, a) };
+
+        let mut cards = self.cards[second + 1..].to_vec();
+        cards.extend_from_slice(&self.cards[first..=second]);
+        cards.extend_from_slice(&self.cards[..first]);
+        self.cards = cards;
+    }
+
+    // Moves the given number of cards from the top to just above the bottom card.
+    fn count_cut(&mut self, count: u8) {
+        let count = Deck::value(count) as usize;
+        self.cards[..53].rotate_left(count);
+    }
+
+    // The count value of a card, where both jokers count as 53.
+    fn value(card: u8) -> u8 {
+        card.min(JOKER_A)
+    }
+}
+
+/// Solitaire Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Solitaire {
+    deck: Deck,
+}
+
+impl Solitaire {
+    /// Initializes a solitaire cipher with a starting deck.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::solitaire::{Deck, Solitaire};
+    ///
+    /// let s = Solitaire::new(Deck::from_passphrase("foo").unwrap());
+    /// ```
+    ///
+    pub fn new(deck: Deck) -> Self {
+        Solitaire { deck }
+    }
+
+    /// Enciphers a message with a solitaire cipher.
+    ///
+    /// Each letter is shifted by the next card of the keystream, counting card
+    /// values modulo 26.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::solitaire::{Deck, Solitaire};
+    ///
+    /// let s = Solitaire::new(Deck::from_passphrase("cryptonomicon").unwrap());
+    /// assert_eq!("KIRAK SFJAN", s.encipher("SOLIT AIREX").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, &'static str> {
+        Vigenere::transpose(&self.keystream(plaintext), plaintext)
+    }
+
+    /// Deciphers a message with a solitaire cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::solitaire::{Deck, Solitaire};
+    ///
+    /// let s = Solitaire::new(Deck::from_passphrase("cryptonomicon").unwrap());
+    /// assert_eq!("SOLIT AIREX", s.decipher("KIRAK SFJAN").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, &'static str> {
+        let filter = self
+            .keystream(ciphertext)
+            .iter()
+            .map(|n| (26 - n) % 26)
+            .collect::<Vec<u8>>();
+        Vigenere::transpose(&filter, ciphertext)
+    }
+
+    // Generates a shift for each letter in the text from a copy of the deck.
+    fn keystream(&self, text: &str) -> Vec<u8> {
+        let mut deck = self.deck.clone();
+        let letters = text.chars().filter(|c| c.is_ascii_alphabetic()).count();
+        (0..letters).map(|_| deck.next_card() % 26).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Deck, Solitaire, JOKER_A, JOKER_B};
+
+    #[test]
+    fn valid_deck() {
+        let order = (1..55).rev().collect::<Vec<u8>>();
+        assert!(Deck::new(&order).is_ok());
+    }
+
+    #[test]
+    fn duplicate_card() {
+        let mut order = (1..55).collect::<Vec<u8>>();
+        order[0] = 2;
+        assert!(Deck::new(&order).is_err());
+    }
+
+    #[test]
+    fn small_deck() {
+        assert!(Deck::new(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn invalid_passphrase() {
+        assert!(Deck::from_passphrase("f00").is_err());
+    }
+
+    #[test]
+    fn joker_wraps_below_top_card() {
+        let mut d = Deck::ordered();
+        d.move_down(JOKER_B, 2);
+        assert_eq!(&[1, 2, JOKER_B], &d.cards()[..3]);
+    }
+
+    #[test]
+    fn first_step() {
+        let mut d = Deck::ordered();
+        assert_eq!(Some(4), d.step());
+        let mut expected = (2..53).collect::<Vec<u8>>();
+        expected.extend_from_slice(&[JOKER_A, JOKER_B, 1]);
+        assert_eq!(expected, d.cards());
+    }
+
+    #[test]
+    fn unkeyed_keystream() {
+        let mut d = Deck::ordered();
+        let keystream = (0..10).map(|_| d.next_card()).collect::<Vec<u8>>();
+        assert_eq!(vec![4, 49, 10, 24, 8, 51, 44, 6, 4, 33], keystream);
+    }
+
+    #[test]
+    fn encipher_unkeyed() {
+        let s = Solitaire::new(Deck::ordered());
+        assert_eq!(
+            "EXKYI ZSGEH UNTIQ",
+            s.encipher("AAAAA AAAAA AAAAA").unwrap()
+        );
+    }
+
+    #[test]
+    fn encipher_with_passphrase() {
+        let s = Solitaire::new(Deck::from_passphrase("foo").unwrap());
+        assert_eq!(
+            "ITHZU JIWGR FARMW",
+            s.encipher("AAAAA AAAAA AAAAA").unwrap()
+        );
+    }
+
+    #[test]
+    fn encipher_cryptonomicon() {
+        let s = Solitaire::new(Deck::from_passphrase("cryptonomicon").unwrap());
+        assert_eq!("KIRAK SFJAN", s.encipher("SOLIT AIREX").unwrap());
+    }
+
+    #[test]
+    fn decipher() {
+        let s = Solitaire::new(Deck::from_passphrase("cryptonomicon").unwrap());
+        assert_eq!("SOLIT AIREX", s.decipher("KIRAK SFJAN").unwrap());
+    }
+}
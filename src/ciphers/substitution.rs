@@ -1,11 +1,44 @@
 use common::ALPHABET;
 
+/// Glyph names for the Pigpen or Masonic cipher, the letters A to I in the plain
+/// grid, J to R in the dotted grid, S to V in the plain X and W to Z in the dotted
+/// X.
+pub const PIGPEN: [&str; 26] = [
+    "[pigpen-a]",
+    "[pigpen-b]",
+    "[pigpen-c]",
+    "[pigpen-d]",
+    "[pigpen-e]",
+    "[pigpen-f]",
+    "[pigpen-g]",
+    "[pigpen-h]",
+    "[pigpen-i]",
+    "[pigpen-j]",
+    "[pigpen-k]",
+    "[pigpen-l]",
+    "[pigpen-m]",
+    "[pigpen-n]",
+    "[pigpen-o]",
+    "[pigpen-p]",
+    "[pigpen-q]",
+    "[pigpen-r]",
+    "[pigpen-s]",
+    "[pigpen-t]",
+    "[pigpen-u]",
+    "[pigpen-v]",
+    "[pigpen-w]",
+    "[pigpen-x]",
+    "[pigpen-y]",
+    "[pigpen-z]",
+];
+
 /// Substitution Cipher
 ///
 /// The struct is generated through the new() function.
 ///
 pub struct Substitution {
-    key: &'static str,
+    key: String,
+    glyphs: Vec<String>,
 }
 
 impl Substitution {
@@ -23,7 +56,7 @@ impl Substitution {
     ///
     /// Will return Err() if the alphabet is not 26 unique alphabetic characters.
     ///
-    pub fn new(key: &'static str) -> Result<Self, String> {
+    pub fn new(key: &str) -> Result<Self, String> {
         if key.chars().count() != 26 {
             return Err(String::from("Key is not the correct length"));
         }
@@ -42,7 +75,71 @@ impl Substitution {
             }
         }
 
-        Ok(Substitution { key })
+        Ok(Substitution {
+            key: String::from(key),
+            glyphs: Vec::new(),
+        })
+    }
+
+    /// Initializes a substitution cipher that replaces the letters A to Z with a
+    /// set of glyphs, which may be any Unicode symbols or names for symbols.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::substitution::Substitution;
+    ///
+    /// let glyphs = "♈♉♊♋♌♍♎♏♐♑♒♓☉☽☿♀♁♂♃♄♅♆♇☄★☆"
+    ///     .chars()
+    ///     .map(|c| c.to_string())
+    ///     .collect::<Vec<String>>();
+    /// let glyphs = glyphs.iter().map(|g| g.as_str()).collect::<Vec<&str>>();
+    /// let s = Substitution::with_glyphs(&glyphs).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if there are not 26 glyphs, a glyph is empty or has
+    /// whitespace, or a glyph begins with another, which would make the ciphertext
+    /// impossible to read back.
+    ///
+    pub fn with_glyphs(glyphs: &[&str]) -> Result<Self, String> {
+        if glyphs.len() != 26 {
+            return Err(String::from("There must be 26 glyphs"));
+        }
+        for (i, g) in glyphs.iter().enumerate() {
+            if g.is_empty() || g.chars().any(char::is_whitespace) {
+                return Err(String::from("Glyphs must not be empty or have whitespace"));
+            }
+            if glyphs
+                .iter()
+                .enumerate()
+                .any(|(j, other)| i != j && other.starts_with(g))
+            {
+                return Err(String::from("Glyphs must not begin with another glyph"));
+            }
+        }
+
+        Ok(Substitution {
+            key: String::from(ALPHABET),
+            glyphs: glyphs.iter().map(|&g| String::from(g)).collect(),
+        })
+    }
+
+    /// Initializes a Pigpen cipher, writing each letter as its glyph name from
+    /// PIGPEN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::substitution::Substitution;
+    ///
+    /// let s = Substitution::pigpen();
+    /// assert_eq!("[pigpen-x][pigpen-o] [pigpen-j]", s.encipher("xo j").unwrap());
+    /// ```
+    ///
+    pub fn pigpen() -> Self {
+        Substitution::with_glyphs(&PIGPEN).unwrap()
     }
 
     /// Enciphers a message with a substitution cipher.
@@ -64,6 +161,9 @@ impl Substitution {
     /// Will panic if there is a missing character or invalid character.
     ///
     pub fn encipher(&self, plaintext: &str) -> Result<String, &'static str> {
+        if !self.glyphs.is_empty() {
+            return Ok(self.encipher_glyphs(plaintext));
+        }
         Ok(plaintext
             .chars()
             .map(|c| match c as u8 {
@@ -112,6 +212,9 @@ impl Substitution {
     /// Will panic if there is a missing character or invalid character.
     ///
     pub fn decipher(&self, plaintext: &str) -> Result<String, &'static str> {
+        if !self.glyphs.is_empty() {
+            return Ok(self.decipher_glyphs(plaintext));
+        }
         Ok(plaintext
             .chars()
             .map(|c| match c as u8 {
@@ -140,11 +243,48 @@ impl Substitution {
             })
             .collect::<String>())
     }
+
+    // Writes each letter as its glyph, leaving other characters unchanged.
+    fn encipher_glyphs(&self, plaintext: &str) -> String {
+        plaintext
+            .chars()
+            .map(|c| match c {
+                'A'..='Z' | 'a'..='z' => {
+                    self.glyphs[(c.to_ascii_uppercase() as u8 - 65) as usize].clone()
+                }
+                _ => c.to_string(),
+            })
+            .collect()
+    }
+
+    // Reads the glyphs back to upper case letters, leaving other characters
+    // unchanged.
+    fn decipher_glyphs(&self, ciphertext: &str) -> String {
+        let mut plaintext = String::new();
+        let mut rest = ciphertext;
+        while let Some(c) = rest.chars().next() {
+            match self
+                .glyphs
+                .iter()
+                .position(|g| rest.starts_with(g.as_str()))
+            {
+                Some(i) => {
+                    plaintext.push((i as u8 + 65) as char);
+                    rest = &rest[self.glyphs[i].len()..];
+                }
+                None => {
+                    plaintext.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        plaintext
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Substitution;
+    use super::{Substitution, PIGPEN};
 
     #[test]
     fn unique_alphabet() {
@@ -206,4 +346,45 @@ mod tests {
             s.decipher("Ye weg fbpq cqkdqh iqccntqc").unwrap()
         );
     }
+
+    #[test]
+    fn glyph_count() {
+        assert!(Substitution::with_glyphs(&PIGPEN[..25]).is_err());
+    }
+
+    #[test]
+    fn glyph_prefix() {
+        let mut glyphs = PIGPEN;
+        glyphs[3] = "[pigpen-a]x";
+        assert!(Substitution::with_glyphs(&glyphs).is_err());
+        glyphs[3] = "[pigpen d]";
+        assert!(Substitution::with_glyphs(&glyphs).is_err());
+    }
+
+    #[test]
+    fn single_character_glyphs() {
+        let glyphs = "αβγδεζηθικλμνξοπρστυφχψωϡϸ"
+            .chars()
+            .map(|c| c.to_string())
+            .collect::<Vec<String>>();
+        let glyphs = glyphs.iter().map(|g| g.as_str()).collect::<Vec<&str>>();
+        let s = Substitution::with_glyphs(&glyphs).unwrap();
+        assert_eq!("υθε δαηηεσ, 1", s.encipher("The dagger, 1").unwrap());
+        assert_eq!("THE DAGGER, 1", s.decipher("υθε δαηηεσ, 1").unwrap());
+    }
+
+    #[test]
+    fn pigpen_encipher() {
+        let s = Substitution::pigpen();
+        assert_eq!("[pigpen-z][pigpen-a]!", s.encipher("Za!").unwrap());
+    }
+
+    #[test]
+    fn pigpen_decipher() {
+        let s = Substitution::pigpen();
+        assert_eq!(
+            "ATTACK AT DAWN",
+            s.decipher(&s.encipher("attack at dawn").unwrap()).unwrap()
+        );
+    }
 }
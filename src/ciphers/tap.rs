@@ -0,0 +1,158 @@
+// The tap code square, read by rows, with C standing for both C and K.
+const SQUARE: &str = "ABCDEFGHIJLMNOPQRSTUVWXYZ";
+
+/// Tap Code
+///
+/// The struct is generated through the new() function.
+///
+pub struct TapCode {}
+
+impl TapCode {
+    /// Initializes a tap code, the knock code of prisoners based on a Polybius
+    /// square in which K is sent as C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::tap::TapCode;
+    ///
+    /// let t = TapCode::new().unwrap();
+    /// ```
+    ///
+    pub fn new() -> Result<Self, String> {
+        Ok(TapCode {})
+    }
+
+    /// Enciphers a message with the tap code.
+    ///
+    /// Each letter is written as a group of dots for its row and a group for its
+    /// column, separated by a space. Letters are separated by two spaces and words
+    /// by a slash. Characters other than letters are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::tap::TapCode;
+    ///
+    /// let t = TapCode::new().unwrap();
+    /// assert_eq!(
+    ///     ". ...  . .....  ... .....  .... .... / ..... ..",
+    ///     t.encipher("Kept w").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        Ok(plaintext
+            .split_whitespace()
+            .map(|word| {
+                word.chars()
+                    .filter(|c| c.is_ascii_alphabetic())
+                    .map(|c| {
+                        let c = match c.to_ascii_uppercase() {
+                            'K' => 'C',
+                            c => c,
+                        };
+                        let i = SQUARE.chars().position(|s| s == c).unwrap();
+                        format!("{} {}", ".".repeat(i / 5 + 1), ".".repeat(i % 5 + 1))
+                    })
+                    .collect::<Vec<String>>()
+                    .join("  ")
+            })
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<String>>()
+            .join(" / "))
+    }
+
+    /// Deciphers a message with the tap code. K cannot be told apart from C and
+    /// is deciphered as C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::tap::TapCode;
+    ///
+    /// let t = TapCode::new().unwrap();
+    /// assert_eq!(
+    ///     "CEPT W",
+    ///     t.decipher(". ...  . .....  ... .....  .... .... / ..... ..").unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if a letter is not two groups of one to five dots.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let mut words = Vec::new();
+        for word in ciphertext.split('/') {
+            let mut letters = String::new();
+            for letter in word.split("  ").map(str::trim).filter(|l| !l.is_empty()) {
+                let taps = letter
+                    .split(' ')
+                    .map(|g| match g.len() {
+                        1..=5 if g.chars().all(|c| c == '.') => Some(g.len() - 1),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<usize>>>();
+                match taps {
+                    Some(ref t) if t.len() == 2 => {
+                        letters.push(SQUARE.chars().nth(t[0] * 5 + t[1]).unwrap())
+                    }
+                    _ => {
+                        return Err(format!(
+                            "'{}' is not two groups of one to five dots",
+                            letter
+                        ))
+                    }
+                }
+            }
+            words.push(letters);
+        }
+        Ok(words.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TapCode;
+
+    #[test]
+    fn encipher() {
+        let t = TapCode::new().unwrap();
+        assert_eq!(
+            "..... ..  . .  .... ....  . .....  .... ..",
+            t.encipher("Water").unwrap()
+        );
+    }
+
+    #[test]
+    fn corners() {
+        let t = TapCode::new().unwrap();
+        assert_eq!(". . / ..... .....", t.encipher("a, z!").unwrap());
+    }
+
+    #[test]
+    fn k_is_c() {
+        let t = TapCode::new().unwrap();
+        assert_eq!(t.encipher("C").unwrap(), t.encipher("k").unwrap());
+    }
+
+    #[test]
+    fn decipher() {
+        let t = TapCode::new().unwrap();
+        assert_eq!(
+            "GOD BLESS",
+            t.decipher(".. ..  ... ....  . .... / . ..  ... .  . .....  .... ...  .... ...")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_taps() {
+        let t = TapCode::new().unwrap();
+        assert!(t.decipher(". ......").is_err());
+        assert!(t.decipher(". . .").is_err());
+        assert!(t.decipher(".").is_err());
+        assert!(t.decipher(". x").is_err());
+    }
+}
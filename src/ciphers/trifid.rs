@@ -0,0 +1,184 @@
+use common::{keyed_alphabet, ALPHABET};
+
+/// Trifid Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Trifid {
+    cube: Vec<char>,
+    period: usize,
+}
+
+impl Trifid {
+    /// Initializes a trifid cipher with a supplied key, 27th symbol and period.
+    ///
+    /// The 3x3x3 cube is filled with the key followed by the remaining letters of
+    /// the alphabet and then the extra symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::trifid::Trifid;
+    ///
+    /// let t = Trifid::new("felix marie delastelle", '+', 5).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key contains characters other than letters,
+    /// spaces or the extra symbol, if the extra symbol is alphabetic or
+    /// whitespace, or if the period is zero.
+    ///
+    pub fn new(key: &str, symbol: char, period: usize) -> Result<Self, String> {
+        if symbol.is_alphabetic() || symbol.is_whitespace() {
+            return Err(String::from("Symbol must not be a letter or whitespace"));
+        }
+        if period == 0 {
+            return Err(String::from("The period must be 1 or greater"));
+        }
+        for c in key.chars() {
+            if c.is_ascii_alphabetic() || c.is_whitespace() || c == symbol {
+                continue;
+            }
+            return Err(String::from("Key must be alphabetic"));
+        }
+
+        let symbols = format!("{}{}", ALPHABET, symbol);
+        Ok(Trifid {
+            cube: keyed_alphabet(key, &symbols).chars().collect(),
+            period,
+        })
+    }
+
+    /// Enciphers a message with a trifid cipher.
+    ///
+    /// Characters that are not in the cube are removed and the result is uppercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::trifid::Trifid;
+    ///
+    /// let t = Trifid::new("felix marie delastelle", '+', 5).unwrap();
+    /// assert_eq!(
+    ///     "FMJFVOISSUFTFPUFEQQC",
+    ///     t.encipher("Aide-toi, le ciel t'aidera").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let coords = self.coordinates(plaintext);
+        let mut ciphertext = String::new();
+
+        for block in coords.chunks(self.period) {
+            let digits = (0..3)
+                .flat_map(|axis| block.iter().map(move |c| c[axis]))
+                .collect::<Vec<usize>>();
+            for t in digits.chunks(3) {
+                ciphertext.push(self.cube[t[0] * 9 + t[1] * 3 + t[2]]);
+            }
+        }
+
+        Ok(ciphertext)
+    }
+
+    /// Deciphers a message with a trifid cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::trifid::Trifid;
+    ///
+    /// let t = Trifid::new("felix marie delastelle", '+', 5).unwrap();
+    /// assert_eq!(
+    ///     "AIDETOILECIELTAIDERA",
+    ///     t.decipher("FMJFVOISSUFTFPUFEQQC").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let coords = self.coordinates(ciphertext);
+        let mut plaintext = String::new();
+
+        for block in coords.chunks(self.period) {
+            let digits = block.iter().flatten().cloned().collect::<Vec<usize>>();
+            let n = block.len();
+            for i in 0..n {
+                plaintext.push(self.cube[digits[i] * 9 + digits[n + i] * 3 + digits[2 * n + i]]);
+            }
+        }
+
+        Ok(plaintext)
+    }
+
+    // Converts the characters in the cube to their layer, row and column.
+    fn coordinates(&self, text: &str) -> Vec<[usize; 3]> {
+        text.chars()
+            .filter_map(|c| {
+                self.cube
+                    .iter()
+                    .position(|&s| s == c.to_ascii_uppercase())
+                    .map(|p| [p / 9, p % 9 / 3, p % 3])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trifid;
+
+    #[test]
+    fn valid_key() {
+        assert!(Trifid::new("felix marie delastelle", '+', 5).is_ok());
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(Trifid::new("f3lix", '+', 5).is_err());
+    }
+
+    #[test]
+    fn alphabetic_symbol() {
+        assert!(Trifid::new("felix", 'a', 5).is_err());
+    }
+
+    #[test]
+    fn zero_period() {
+        assert!(Trifid::new("felix", '+', 0).is_err());
+    }
+
+    #[test]
+    fn cube_layout() {
+        let t = Trifid::new("felix marie delastelle", '+', 5).unwrap();
+        assert_eq!(
+            "FELIXMARDSTBCGHJKNOPQUVWYZ+",
+            t.cube.iter().collect::<String>()
+        );
+    }
+
+    #[test]
+    fn encipher() {
+        let t = Trifid::new("felix marie delastelle", '+', 5).unwrap();
+        assert_eq!(
+            "FMJFVOISSUFTFPUFEQQC",
+            t.encipher("Aide-toi, le ciel t'aidera").unwrap()
+        );
+    }
+
+    #[test]
+    fn decipher() {
+        let t = Trifid::new("felix marie delastelle", '+', 5).unwrap();
+        assert_eq!(
+            "AIDETOILECIELTAIDERA",
+            t.decipher("FMJFVOISSUFTFPUFEQQC").unwrap()
+        );
+    }
+
+    #[test]
+    fn with_symbol() {
+        let t = Trifid::new("extraordinary", '.', 7).unwrap();
+        let ciphertext = t.encipher("trifids are fractionating.").unwrap();
+        assert_eq!("TRIFIDSAREFRACTIONATING.", t.decipher(&ciphertext).unwrap());
+    }
+}
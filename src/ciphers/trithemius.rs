@@ -0,0 +1,125 @@
+use ciphers::vigenere::Vigenere;
+
+/// Trithemius Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Trithemius {
+    shift: u8,
+    step: u8,
+}
+
+impl Trithemius {
+    /// Initializes a trithemius cipher with a starting shift and the step the shift
+    /// increases by after each letter.
+    ///
+    /// The classic cipher, which walks down the tabula recta, starts with a shift
+    /// of 0 and a step of 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::trithemius::Trithemius;
+    ///
+    /// let t = Trithemius::new(0, 1).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the shift or step is not between 0 and 25.
+    ///
+    pub fn new(shift: u8, step: u8) -> Result<Self, String> {
+        if shift > 25 || step > 25 {
+            Err(String::from("Shift and step must be between 0 through 25"))
+        } else {
+            Ok(Trithemius { shift, step })
+        }
+    }
+
+    /// Enciphers a message with a trithemius cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::trithemius::Trithemius;
+    ///
+    /// let t = Trithemius::new(0, 1).unwrap();
+    /// assert_eq!("auvdgp ga ljgy", t.encipher("attack at dawn").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, &'static str> {
+        Vigenere::transpose(&self.keystream(), plaintext)
+    }
+
+    /// Deciphers a message with a trithemius cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::trithemius::Trithemius;
+    ///
+    /// let t = Trithemius::new(0, 1).unwrap();
+    /// assert_eq!("attack at dawn", t.decipher("auvdgp ga ljgy").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, &'static str> {
+        let filter = self
+            .keystream()
+            .iter()
+            .map(|n| (26 - n) % 26)
+            .collect::<Vec<u8>>();
+        Vigenere::transpose(&filter, ciphertext)
+    }
+
+    // Calculates the shifts for one full cycle of the progression.
+    fn keystream(&self) -> Vec<u8> {
+        (0..26u16)
+            .map(|i| ((self.shift as u16 + i * self.step as u16) % 26) as u8)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trithemius;
+
+    #[test]
+    fn valid_settings() {
+        assert!(Trithemius::new(3, 7).is_ok());
+    }
+
+    #[test]
+    fn invalid_shift() {
+        assert!(Trithemius::new(26, 1).is_err());
+    }
+
+    #[test]
+    fn invalid_step() {
+        assert!(Trithemius::new(0, 26).is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let t = Trithemius::new(0, 1).unwrap();
+        assert_eq!("auvdgp ga ljgy", t.encipher("attack at dawn").unwrap());
+    }
+
+    #[test]
+    fn decipher() {
+        let t = Trithemius::new(0, 1).unwrap();
+        assert_eq!("attack at dawn", t.decipher("auvdgp ga ljgy").unwrap());
+    }
+
+    #[test]
+    fn long_message() {
+        let t = Trithemius::new(5, 3).unwrap();
+        let alpha = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        assert_eq!(alpha, t.decipher(&t.encipher(alpha).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn with_punctuation() {
+        let t = Trithemius::new(2, 2).unwrap();
+        assert_eq!("Jirty, U", t.encipher("Hello, I").unwrap());
+    }
+}
@@ -0,0 +1,408 @@
+use common::{keyed_alphabet, ALPHABET};
+
+/// Arrangement of the two squares in a two-square cipher.
+///
+/// With a horizontal layout the first letter of each digraph is found in the
+/// left square and the second in the right. With a vertical layout the first
+/// letter is found in the upper square and the second in the lower.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Layout {
+    Horizontal,
+    Vertical,
+}
+
+/// Two-Square Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct TwoSquare {
+    first: Vec<char>,
+    second: Vec<char>,
+    layout: Layout,
+    omitted: char,
+}
+
+impl TwoSquare {
+    /// Initializes a two-square cipher with two keys and a layout.
+    ///
+    /// The first key fills the left or upper square and the second key fills the
+    /// right or lower square. J is merged into I.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::twosquare::{Layout, TwoSquare};
+    ///
+    /// let t = TwoSquare::new("example", "keyword", Layout::Vertical).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if either key is not alphabetic.
+    ///
+    pub fn new(first: &str, second: &str, layout: Layout) -> Result<Self, String> {
+        TwoSquare::with_omitted(first, second, layout, 'J')
+    }
+
+    /// Initializes a two-square cipher with two keys, a layout and the letter
+    /// left out of both squares.
+    ///
+    /// An omitted J is merged into I, as with new(). Any other omitted letter is
+    /// removed from keys and messages, as Q often is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::twosquare::{Layout, TwoSquare};
+    ///
+    /// let t = TwoSquare::with_omitted("example", "keyword", Layout::Vertical, 'q').unwrap();
+    /// let (upper, _) = t.squares();
+    /// assert_eq!(vec!['G', 'H', 'I', 'J', 'K'], upper[2]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if either key is not alphabetic, or the omitted
+    /// character is not a letter.
+    ///
+    pub fn with_omitted(
+        first: &str,
+        second: &str,
+        layout: Layout,
+        omitted: char,
+    ) -> Result<Self, String> {
+        for c in first.chars().chain(second.chars()) {
+            if c.is_ascii_alphabetic() {
+                continue;
+            }
+            return Err(String::from("Keys must be alphabetic"));
+        }
+        if !omitted.is_ascii_alphabetic() {
+            return Err(String::from("Omitted character must be a letter"));
+        }
+
+        let omitted = omitted.to_ascii_uppercase();
+        let alphabet = ALPHABET.replace(omitted, "");
+        let square = |key: &str| {
+            let key = TwoSquare::prepare(key, omitted)
+                .into_iter()
+                .collect::<String>();
+            keyed_alphabet(&key, &alphabet).chars().collect()
+        };
+
+        Ok(TwoSquare {
+            first: square(first),
+            second: square(second),
+            layout,
+            omitted,
+        })
+    }
+
+    /// Enciphers a message with a two-square cipher.
+    ///
+    /// Non-alphabetic characters and the omitted letter are removed, except that
+    /// an omitted J is replaced with I, and an X is appended to messages of odd
+    /// length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::twosquare::{Layout, TwoSquare};
+    ///
+    /// let t = TwoSquare::new("example", "keyword", Layout::Vertical).unwrap();
+    /// assert_eq!(
+    ///     "HECMXWSRKYXPHWNODG",
+    ///     t.encipher("Help me Obi Wan Kenobi").unwrap()
+    /// );
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        let mut letters = TwoSquare::prepare(plaintext, self.omitted);
+        if letters.len() % 2 == 1 {
+            letters.push('X');
+        }
+        self.transpose(&letters, false)
+    }
+
+    /// Deciphers a message with a two-square cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::twosquare::{Layout, TwoSquare};
+    ///
+    /// let t = TwoSquare::new("example", "keyword", Layout::Vertical).unwrap();
+    /// assert_eq!(
+    ///     "HELPMEOBIWANKENOBI",
+    ///     t.decipher("HECMXWSRKYXPHWNODG").unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the ciphertext has an odd number of letters.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let letters = TwoSquare::prepare(ciphertext, self.omitted);
+        if letters.len() % 2 == 1 {
+            return Err(String::from(
+                "Ciphertext must have an even number of letters",
+            ));
+        }
+        self.transpose(&letters, true)
+    }
+
+    /// Returns the two squares as rows of letters, in the order left then right
+    /// or upper then lower.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::twosquare::{Layout, TwoSquare};
+    ///
+    /// let t = TwoSquare::new("example", "keyword", Layout::Vertical).unwrap();
+    /// let (upper, lower) = t.squares();
+    /// assert_eq!(vec!['E', 'X', 'A', 'M', 'P'], upper[0]);
+    /// assert_eq!(vec!['K', 'E', 'Y', 'W', 'O'], lower[0]);
+    /// ```
+    ///
+    pub fn squares(&self) -> (Vec<Vec<char>>, Vec<Vec<char>>) {
+        (
+            self.first.chunks(5).map(|r| r.to_vec()).collect(),
+            self.second.chunks(5).map(|r| r.to_vec()).collect(),
+        )
+    }
+
+    // Substitutes each digraph with the opposite corners of its rectangle.
+    //
+    // The first output letter shares the row of the first input letter and the
+    // column of the second. It is taken from the upper square in a vertical
+    // layout and the right square in a horizontal one, so digraphs in the same
+    // column or row respectively are left unchanged or reversed.
+    fn transpose(&self, letters: &[char], decipher: bool) -> Result<String, String> {
+        let (mut a_square, mut b_square) = (&self.first, &self.second);
+        if self.layout == Layout::Horizontal {
+            std::mem::swap(&mut a_square, &mut b_square);
+        }
+        let (in_first, in_second, out_first, out_second) = if decipher {
+            (a_square, b_square, &self.first, &self.second)
+        } else {
+            (&self.first, &self.second, a_square, b_square)
+        };
+
+        let mut result = String::new();
+        for pair in letters.chunks(2) {
+            let a = in_first.iter().position(|&c| c == pair[0]).unwrap();
+            let b = in_second.iter().position(|&c| c == pair[1]).unwrap();
+            result.push(out_first[a / 5 * 5 + b % 5]);
+            result.push(out_second[b / 5 * 5 + a % 5]);
+        }
+
+        Ok(result)
+    }
+
+    // Uppercases the letters of a message, merging an omitted J into I and
+    // removing any other omitted letter.
+    fn prepare(text: &str, omitted: char) -> Vec<char> {
+        text.chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .map(|c| match c.to_ascii_uppercase() {
+                'J' if omitted == 'J' => 'I',
+                c => c,
+            })
+            .filter(|&c| c != omitted)
+            .collect()
+    }
+}
+
+/// Doppelkasten Cipher
+///
+/// The German double box variant of the horizontal two-square cipher, in which
+/// the message is enciphered a second time with the same squares.
+///
+/// This is a simplified form. The wartime procedure wrote the message in fixed
+/// periods before forming digraphs and had its own rule for digraphs in the
+/// same row. Here the whole message is taken in consecutive digraphs for both
+/// passes, with the standard two-square rule.
+///
+/// The struct is generated through the new() function.
+///
+pub struct Doppelkasten {
+    squares: TwoSquare,
+}
+
+impl Doppelkasten {
+    /// Initializes a doppelkasten cipher with keys for the left and right boxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::twosquare::Doppelkasten;
+    ///
+    /// let d = Doppelkasten::new("wetter", "bericht").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if either key is not alphabetic.
+    ///
+    pub fn new(left: &str, right: &str) -> Result<Self, String> {
+        Ok(Doppelkasten {
+            squares: TwoSquare::new(left, right, Layout::Horizontal)?,
+        })
+    }
+
+    /// Enciphers a message with a doppelkasten cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::twosquare::Doppelkasten;
+    ///
+    /// let d = Doppelkasten::new("wetter", "bericht").unwrap();
+    /// assert_eq!("DIRGUWAQSHHOUQ", d.encipher("Angriff um neun").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        self.squares.encipher(&self.squares.encipher(plaintext)?)
+    }
+
+    /// Deciphers a message with a doppelkasten cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::twosquare::Doppelkasten;
+    ///
+    /// let d = Doppelkasten::new("wetter", "bericht").unwrap();
+    /// assert_eq!("ANGRIFFUMNEUNX", d.decipher("DIRGUWAQSHHOUQ").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the ciphertext has an odd number of letters.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        self.squares.decipher(&self.squares.decipher(ciphertext)?)
+    }
+
+    /// Returns the left and right boxes as rows of letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::twosquare::Doppelkasten;
+    ///
+    /// let d = Doppelkasten::new("wetter", "bericht").unwrap();
+    /// let (left, right) = d.squares();
+    /// assert_eq!(vec!['W', 'E', 'T', 'R', 'A'], left[0]);
+    /// assert_eq!(vec!['B', 'E', 'R', 'I', 'C'], right[0]);
+    /// ```
+    ///
+    pub fn squares(&self) -> (Vec<Vec<char>>, Vec<Vec<char>>) {
+        self.squares.squares()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Doppelkasten, Layout, TwoSquare};
+
+    #[test]
+    fn valid_keys() {
+        assert!(TwoSquare::new("example", "keyword", Layout::Horizontal).is_ok());
+    }
+
+    #[test]
+    fn invalid_keys() {
+        assert!(TwoSquare::new("example", "k3yword", Layout::Horizontal).is_err());
+    }
+
+    #[test]
+    fn invalid_omitted() {
+        assert!(TwoSquare::with_omitted("example", "keyword", Layout::Vertical, '1').is_err());
+    }
+
+    #[test]
+    fn omitted_q_squares() {
+        let t = TwoSquare::with_omitted("example", "keyword", Layout::Vertical, 'Q').unwrap();
+        let (upper, lower) = t.squares();
+        assert_eq!(
+            "EXAMPLBCDFGHIJKNORSTUVWYZ",
+            upper.concat().iter().collect::<String>()
+        );
+        assert_eq!(
+            "KEYWORDABCFGHIJLMNPSTUVXZ",
+            lower.concat().iter().collect::<String>()
+        );
+    }
+
+    #[test]
+    fn published_vertical() {
+        let t = TwoSquare::with_omitted("example", "keyword", Layout::Vertical, 'Q').unwrap();
+        assert_eq!(
+            "HEDLXWSDJYANHOTKDG",
+            t.encipher("Help me Obi Wan Kenobi").unwrap()
+        );
+        assert_eq!(
+            "HELPMEOBIWANKENOBI",
+            t.decipher("HEDLXWSDJYANHOTKDG").unwrap()
+        );
+    }
+
+    #[test]
+    fn encipher_vertical() {
+        let t = TwoSquare::new("example", "keyword", Layout::Vertical).unwrap();
+        assert_eq!(
+            "HECMXWSRKYXPHWNODG",
+            t.encipher("Help me Obi Wan Kenobi").unwrap()
+        );
+    }
+
+    #[test]
+    fn decipher_vertical() {
+        let t = TwoSquare::new("example", "keyword", Layout::Vertical).unwrap();
+        assert_eq!(
+            "HELPMEOBIWANKENOBI",
+            t.decipher("HECMXWSRKYXPHWNODG").unwrap()
+        );
+    }
+
+    #[test]
+    fn vertical_same_column() {
+        let t = TwoSquare::new("example", "keyword", Layout::Vertical).unwrap();
+        assert_eq!("HE", t.encipher("he").unwrap());
+    }
+
+    #[test]
+    fn horizontal_same_row() {
+        let t = TwoSquare::new("example", "keyword", Layout::Horizontal).unwrap();
+        assert_eq!("KE", t.encipher("ek").unwrap());
+    }
+
+    #[test]
+    fn horizontal_round_trip() {
+        let t = TwoSquare::new("example", "keyword", Layout::Horizontal).unwrap();
+        let ciphertext = t.encipher("Jedi knights are here").unwrap();
+        assert_eq!("IEDIKNIGHTSAREHERE", t.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn odd_ciphertext() {
+        let t = TwoSquare::new("example", "keyword", Layout::Horizontal).unwrap();
+        assert!(t.decipher("ABC").is_err());
+    }
+
+    #[test]
+    fn doppelkasten_encipher() {
+        let d = Doppelkasten::new("wetter", "bericht").unwrap();
+        assert_eq!("DIRGUWAQSHHOUQ", d.encipher("Angriff um neun").unwrap());
+    }
+
+    #[test]
+    fn doppelkasten_decipher() {
+        let d = Doppelkasten::new("wetter", "bericht").unwrap();
+        assert_eq!("ANGRIFFUMNEUNX", d.decipher("DIRGUWAQSHHOUQ").unwrap());
+    }
+}
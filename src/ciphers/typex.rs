@@ -0,0 +1,236 @@
+use ciphers::rotor::{step_with_pawls, Pairs, Rotor, RotorSpec};
+use common::ALPHABET;
+
+/// The setup of a Typex machine.
+///
+/// The moving rotors are listed from left to right, so the last is the fast
+/// rotor, and the stators sit between the fast rotor and the entry. Typex
+/// rotors usually carry several notches and may be inserted reversed. The
+/// reflector is written as the letter each letter is wired to, and the
+/// plugboard as pairs of letters such as "AB CD", or empty for no plugboard.
+///
+/// No rotor or reflector tables are shipped with the crate, as the historical
+/// Typex wirings have not been checked here. See EXAMPLE for a setup to try the
+/// machine with.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TypexConfig<'a> {
+    pub reflector: &'a str,
+    pub rotors: [RotorSpec<'a>; 3],
+    pub stators: [RotorSpec<'a>; 2],
+    pub plugboard: &'a str,
+}
+
+/// An example Typex setup with every rotor at A and no plugboard.
+///
+/// The Enigma I to V wirings stand in for the rotors and stators and the Enigma
+/// B reflector for the reflector, so it checks the stepping and round trips
+/// rather than reproducing a real Typex message.
+///
+pub const EXAMPLE: TypexConfig<'static> = TypexConfig {
+    reflector: "YRUHQSLDPXNGOKMIEBFZCWVJAT",
+    rotors: [
+        rotor("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "ACEINQTVY"),
+        rotor("AJDKSIRUXBLHWTMCQGZNPYFVOE", "BDGKNRUX"),
+        rotor("BDFHJLCPRTXVZNYEIWGAKMUSQO", "CFJMPSW"),
+    ],
+    stators: [
+        rotor("ESOVPZJAYQUIRHXLNFTGKDCMWB", ""),
+        rotor("VZBRGITYUPSDNHLXAWMJQOFECK", ""),
+    ],
+    plugboard: "",
+};
+
+const fn rotor(wiring: &'static str, notches: &'static str) -> RotorSpec<'static> {
+    RotorSpec {
+        wiring,
+        notches,
+        ring: 'A',
+        position: 'A',
+        reversed: false,
+    }
+}
+
+/// Typex Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct Typex {
+    reflector: Pairs,
+    rotors: Vec<Rotor>,
+    stators: Vec<Rotor>,
+    plugboard: Pairs,
+}
+
+impl Typex {
+    /// Initializes a Typex machine from its configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::typex::{Typex, EXAMPLE};
+    ///
+    /// let t = Typex::new(&EXAMPLE).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if a rotor or stator is not wired to each letter once or
+    /// has a setting that is not a letter, the reflector does not pair every letter
+    /// with another, or the plugboard pairs a letter twice.
+    ///
+    pub fn new(config: &TypexConfig) -> Result<Self, String> {
+        Ok(Typex {
+            reflector: Pairs::reflector(config.reflector)?,
+            rotors: config
+                .rotors
+                .iter()
+                .map(|spec| Rotor::new(spec, ALPHABET))
+                .collect::<Result<Vec<Rotor>, String>>()?,
+            stators: config
+                .stators
+                .iter()
+                .map(|spec| Rotor::new(spec, ALPHABET))
+                .collect::<Result<Vec<Rotor>, String>>()?,
+            plugboard: Pairs::new(config.plugboard)?,
+        })
+    }
+
+    /// Enciphers a message with a Typex machine.
+    ///
+    /// The case of letters is kept and other characters are left unchanged
+    /// without stepping the rotors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::typex::{Typex, EXAMPLE};
+    ///
+    /// let t = Typex::new(&EXAMPLE).unwrap();
+    /// let ciphertext = t.encipher("Attack at dawn").unwrap();
+    /// assert_eq!("Attack at dawn", t.decipher(&ciphertext).unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        Ok(self.crypt(plaintext))
+    }
+
+    /// Deciphers a message with a Typex machine, which is its own inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::typex::{Typex, EXAMPLE};
+    ///
+    /// let t = Typex::new(&EXAMPLE).unwrap();
+    /// let ciphertext = t.encipher("Attack at dawn").unwrap();
+    /// assert_eq!("Attack at dawn", t.decipher(&ciphertext).unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        Ok(self.crypt(ciphertext))
+    }
+
+    // Steps the rotors for each letter and passes it through the stators and
+    // rotors to the reflector and back.
+    fn crypt(&self, text: &str) -> String {
+        let mut rotors = self.rotors.clone();
+        text.chars()
+            .map(|c| {
+                if !c.is_ascii_alphabetic() {
+                    return c;
+                }
+                step_with_pawls(&mut rotors);
+
+                let mut contact = self.plugboard.swap(c.to_ascii_uppercase() as usize - 65);
+                for rotor in self.stators.iter().rev().chain(rotors.iter().rev()) {
+                    contact = rotor.forward(contact);
+                }
+                contact = self.reflector.swap(contact);
+                for rotor in rotors.iter().chain(self.stators.iter()) {
+                    contact = rotor.backward(contact);
+                }
+                let output = (self.plugboard.swap(contact) as u8 + 65) as char;
+
+                if c.is_ascii_lowercase() {
+                    output.to_ascii_lowercase()
+                } else {
+                    output
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Typex, EXAMPLE};
+    use ciphers::rotor::RotorSpec;
+
+    const STRAIGHT: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    #[test]
+    fn invalid_reflector() {
+        let mut c = EXAMPLE;
+        c.reflector = STRAIGHT;
+        assert!(Typex::new(&c).is_err());
+    }
+
+    #[test]
+    fn invalid_stator() {
+        let mut c = EXAMPLE;
+        c.stators[0].position = '1';
+        assert!(Typex::new(&c).is_err());
+    }
+
+    #[test]
+    fn enigma_with_straight_stators() {
+        // With straight stators and single notches the Typex behaves as an
+        // Enigma I with rotors I, II and III and reflector B.
+        let mut c = EXAMPLE;
+        c.rotors[0].notches = "Q";
+        c.rotors[1].notches = "E";
+        c.rotors[2].notches = "V";
+        c.stators = [RotorSpec::new(STRAIGHT, ""), RotorSpec::new(STRAIGHT, "")];
+        let t = Typex::new(&c).unwrap();
+        assert_eq!("BDZGO", t.encipher("AAAAA").unwrap());
+    }
+
+    #[test]
+    fn no_letter_enciphers_to_itself() {
+        let t = Typex::new(&EXAMPLE).unwrap();
+        let plaintext = "A".repeat(200);
+        assert!(!t.encipher(&plaintext).unwrap().contains('A'));
+    }
+
+    #[test]
+    fn round_trip_with_settings() {
+        let mut c = EXAMPLE;
+        c.rotors[0].reversed = true;
+        c.rotors[1].ring = 'K';
+        c.rotors[2].position = 'W';
+        c.stators[1].position = 'M';
+        c.plugboard = "AQ BT MZ";
+        let t = Typex::new(&c).unwrap();
+        let plaintext = "Typex kept its messages safe, 1940!";
+        let ciphertext = t.encipher(plaintext).unwrap();
+        assert_ne!(plaintext, ciphertext);
+        assert_eq!(plaintext, t.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn multiple_notches_turn_the_middle_rotor() {
+        // The fast rotor starts at B, one before its first notch at C, so the
+        // middle rotor moves on the second letter as well as on later notches.
+        let mut c = EXAMPLE;
+        c.rotors[2].position = 'B';
+        let single = {
+            let mut s = c;
+            s.rotors[2].notches = "V";
+            Typex::new(&s).unwrap().encipher("AAAAAAAA").unwrap()
+        };
+        let multiple = Typex::new(&c).unwrap().encipher("AAAAAAAA").unwrap();
+        assert_eq!(single[..1], multiple[..1]);
+        assert_ne!(single, multiple);
+    }
+}
@@ -0,0 +1,604 @@
+use ciphers::columnar::{Columnar, DisruptedColumnar};
+
+/// The standard straddling checkerboard, with the letters of AT ONE SIR on the
+/// top row and two blank cells whose column digits start the lower rows.
+pub const CHECKERBOARD: &str = "AT ONE SIRBCDFGHJKLMPQUVWXYZ/.";
+
+/// The lines of the VIC key derivation, named by the letters of the lines in the
+/// Hayhanen instructions.
+///
+/// Line D holds the first twenty letters of the phrase and lines E and F are each
+/// written as two halves of ten digits. The block holds lines K to P, and the key
+/// lengths are the personal number added to the last two unequal digits of line
+/// P. Lines Q and R are the keys of the two transpositions and line S numbers the
+/// columns of the checkerboard.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keys {
+    pub a: String,
+    pub b: String,
+    pub c: String,
+    pub d: String,
+    pub e: String,
+    pub f: String,
+    pub g: String,
+    pub h: String,
+    pub j: String,
+    pub block: Vec<String>,
+    pub lengths: (usize, usize),
+    pub q: String,
+    pub r: String,
+    pub s: String,
+}
+
+/// The stages a message goes through when enciphered with the VIC cipher.
+///
+/// The message is encoded as digits with the checkerboard, padded to whole
+/// groups of five and ended with the number of pad digits, put through the
+/// columnar and then the disrupted transposition, and finally printed in groups
+/// with the message indicator inserted.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stages {
+    pub keys: Keys,
+    pub encoded: String,
+    pub first: String,
+    pub second: String,
+    pub ciphertext: String,
+}
+
+/// VIC Cipher
+///
+/// The key derivation and transpositions follow descriptions of the Hayhanen
+/// instructions, but they have not been checked against the Hayhanen message or
+/// another published worked example, so the output may differ from a real VIC
+/// message.
+///
+/// The struct is generated through the new() function.
+///
+pub struct Vic {
+    phrase: Vec<char>,
+    date: Vec<u8>,
+    personal: usize,
+    board: Vec<char>,
+}
+
+impl Vic {
+    /// Initializes a VIC cipher with the agent's key phrase, the date written as
+    /// six digits, the agent's personal number and a straddling checkerboard.
+    ///
+    /// Only the first twenty letters of the phrase are used. The checkerboard is
+    /// written as its three rows of ten, with two spaces for the blank cells of
+    /// the top row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::vic::{Vic, CHECKERBOARD};
+    ///
+    /// let v = Vic::new(
+    ///     "I dream of Jeannie with the light brown hair",
+    ///     "130959",
+    ///     8,
+    ///     CHECKERBOARD,
+    /// ).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the phrase has fewer than twenty letters, the date is
+    /// not six digits, the personal number is not from 1 to 15, or the
+    /// checkerboard does not have two blank cells on its top row and twenty eight
+    /// different characters.
+    ///
+    pub fn new(phrase: &str, date: &str, personal: usize, board: &str) -> Result<Self, String> {
+        let phrase = phrase
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .flat_map(|c| c.to_uppercase())
+            .take(20)
+            .collect::<Vec<char>>();
+        if phrase.len() < 20 {
+            return Err(String::from("Phrase must have at least 20 letters"));
+        }
+
+        if date.len() != 6 || !date.chars().all(|c| c.is_ascii_digit()) {
+            return Err(String::from("Date must be six digits"));
+        }
+        let date = date.bytes().map(|b| b - b'0').collect();
+
+        if personal == 0 || personal > 15 {
+            return Err(String::from("Personal number must be from 1 to 15"));
+        }
+
+        let board = board
+            .chars()
+            .flat_map(|c| c.to_uppercase())
+            .collect::<Vec<char>>();
+        if board.len() != 30
+            || board[..10].iter().filter(|&&c| c == ' ').count() != 2
+            || board[10..].contains(&' ')
+            || board
+                .iter()
+                .enumerate()
+                .any(|(i, &c)| c != ' ' && board[..i].contains(&c))
+        {
+            return Err(String::from(
+                "Checkerboard must be 28 different characters with two blanks on the top row",
+            ));
+        }
+
+        Ok(Vic {
+            phrase,
+            date,
+            personal,
+            board,
+        })
+    }
+
+    /// Derives the keys for a message from the five digit message indicator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::vic::{Vic, CHECKERBOARD};
+    ///
+    /// let v = Vic::new(
+    ///     "I dream of Jeannie with the light brown hair",
+    ///     "130959",
+    ///     8,
+    ///     CHECKERBOARD,
+    /// ).unwrap();
+    /// let keys = v.keys("72401").unwrap();
+    /// assert_eq!("69416", keys.c);
+    /// assert_eq!((11, 12), keys.lengths);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the indicator is not five digits.
+    ///
+    pub fn keys(&self, indicator: &str) -> Result<Keys, String> {
+        if indicator.len() != 5 || !indicator.chars().all(|c| c.is_ascii_digit()) {
+            return Err(String::from("Indicator must be five digits"));
+        }
+        let a = indicator.bytes().map(|b| b - b'0').collect::<Vec<u8>>();
+        let b = self.date[..5].to_vec();
+        let c = a
+            .iter()
+            .zip(b.iter())
+            .map(|(x, y)| (x + 10 - y) % 10)
+            .collect::<Vec<u8>>();
+
+        let e = [sequence(&self.phrase[..10]), sequence(&self.phrase[10..])];
+        let f = [chain(&c, 10), vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0]];
+        let g = e[0]
+            .iter()
+            .zip(f[0].iter())
+            .map(|(x, y)| (x + y) % 10)
+            .collect::<Vec<u8>>();
+        let h = g
+            .iter()
+            .map(|&d| e[1][(d as usize + 9) % 10])
+            .collect::<Vec<u8>>();
+        let j = sequence(&tens(&h));
+
+        let block = chain(&h, 60)[10..].to_vec();
+        let p = &block[40..];
+        let last = p[9];
+        let other = p
+            .iter()
+            .rev()
+            .find(|&&d| d != last)
+            .cloned()
+            .unwrap_or(last);
+        let length = |d: u8| self.personal + if d == 0 { 10 } else { d as usize };
+        let lengths = (length(other), length(last));
+
+        let mut columns = Vec::new();
+        for rank in (1..=10).map(|r| r % 10) {
+            let col = j.iter().position(|&d| d == rank).unwrap();
+            columns.extend((0..5).map(|row| block[row * 10 + col]));
+        }
+        let q = columns[..lengths.0].to_vec();
+        let r = columns[lengths.0..lengths.0 + lengths.1].to_vec();
+        let s = sequence(&tens(p));
+
+        Ok(Keys {
+            a: digits(&a),
+            b: digits(&b),
+            c: digits(&c),
+            d: self.phrase.iter().collect(),
+            e: digits(&e.concat()),
+            f: digits(&f.concat()),
+            g: digits(&g),
+            h: digits(&h),
+            j: digits(&j),
+            block: block.chunks(10).map(digits).collect(),
+            lengths,
+            q: digits(&q),
+            r: digits(&r),
+            s: digits(&s),
+        })
+    }
+
+    /// Enciphers a message with the VIC cipher, showing each stage of the work.
+    ///
+    /// Spaces are removed and the other characters must be on the checkerboard.
+    /// The message is padded with the last character of the checkerboard and
+    /// ended with a digit giving the number of pad digits, so that it fills whole
+    /// groups of five. The indicator is inserted as the group that is as many groups from the end as
+    /// the last digit of the date, or 10 for a 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::vic::{Vic, CHECKERBOARD};
+    ///
+    /// let v = Vic::new(
+    ///     "I dream of Jeannie with the light brown hair",
+    ///     "130959",
+    ///     8,
+    ///     CHECKERBOARD,
+    /// ).unwrap();
+    /// let stages = v.stages("Attack at dawn", "72401").unwrap();
+    /// assert_eq!(stages.ciphertext, v.encipher("Attack at dawn", "72401").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the indicator is not five digits or the message has a
+    /// character that is not on the checkerboard.
+    ///
+    pub fn stages(&self, plaintext: &str, indicator: &str) -> Result<Stages, String> {
+        let keys = self.keys(indicator)?;
+        let codes = self.codes(&keys.s);
+
+        let mut encoded = String::new();
+        for c in plaintext
+            .chars()
+            .filter(|&c| c != ' ')
+            .flat_map(|c| c.to_uppercase())
+        {
+            match codes.iter().find(|&&(symbol, _)| symbol == c) {
+                Some((_, code)) => encoded.push_str(code),
+                None => return Err(format!("'{}' is not on the checkerboard", c)),
+            }
+        }
+        let pad = &codes[codes.len() - 1].1;
+        let mut count = 0;
+        for d in pad.chars().cycle() {
+            if (encoded.len() + 1).is_multiple_of(5) {
+                break;
+            }
+            encoded.push(d);
+            count += 1;
+        }
+        encoded.push_str(&count.to_string());
+
+        let first = Columnar::new(&key_letters(&keys.q))?.encipher(&encoded)?;
+        let second = DisruptedColumnar::new(&key_letters(&keys.r))?.encipher(&first)?;
+
+        let mut groups = second
+            .as_bytes()
+            .chunks(5)
+            .map(|g| String::from_utf8(g.to_vec()).unwrap())
+            .collect::<Vec<String>>();
+        let at = groups.len().saturating_sub(self.indicator_place() - 1);
+        groups.insert(at, String::from(indicator));
+
+        Ok(Stages {
+            keys,
+            encoded,
+            first,
+            second,
+            ciphertext: groups.join(" "),
+        })
+    }
+
+    /// Enciphers a message with the VIC cipher and a five digit message
+    /// indicator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::vic::{Vic, CHECKERBOARD};
+    ///
+    /// let v = Vic::new(
+    ///     "I dream of Jeannie with the light brown hair",
+    ///     "130959",
+    ///     8,
+    ///     CHECKERBOARD,
+    /// ).unwrap();
+    /// let ciphertext = v.encipher("Attack at dawn", "72401").unwrap();
+    /// assert_eq!("ATTACKATDAWN", v.decipher(&ciphertext).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the indicator is not five digits or the message has a
+    /// character that is not on the checkerboard.
+    ///
+    pub fn encipher(&self, plaintext: &str, indicator: &str) -> Result<String, String> {
+        Ok(self.stages(plaintext, indicator)?.ciphertext)
+    }
+
+    /// Deciphers a message with the VIC cipher, finding the message indicator
+    /// from the date.
+    ///
+    /// The padding at the end of the message is removed, using the count of pad
+    /// digits that the message ends with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::vic::{Vic, CHECKERBOARD};
+    ///
+    /// let v = Vic::new(
+    ///     "I dream of Jeannie with the light brown hair",
+    ///     "130959",
+    ///     8,
+    ///     CHECKERBOARD,
+    /// ).unwrap();
+    /// let ciphertext = v.encipher("We are discovered", "72401").unwrap();
+    /// assert_eq!("WEAREDISCOVERED", v.decipher(&ciphertext).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the ciphertext is not groups of five digits or does
+    /// not end with a valid count of pad digits.
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        let mut groups = ciphertext
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<String>>();
+        if groups.len() < 2
+            || groups
+                .iter()
+                .any(|g| g.len() != 5 || !g.chars().all(|c| c.is_ascii_digit()))
+        {
+            return Err(String::from("Ciphertext must be groups of five digits"));
+        }
+
+        let at = groups.len().saturating_sub(self.indicator_place());
+        let indicator = groups.remove(at);
+        let keys = self.keys(&indicator)?;
+
+        let second = groups.concat();
+        let first = DisruptedColumnar::new(&key_letters(&keys.r))?.decipher(&second)?;
+        let mut encoded = Columnar::new(&key_letters(&keys.q))?
+            .decipher(&first)?
+            .bytes()
+            .map(|b| b - b'0')
+            .collect::<Vec<u8>>();
+        let count = encoded.pop().unwrap() as usize;
+        if count > 4 || count > encoded.len() {
+            return Err(String::from("Ciphertext has an invalid padding count"));
+        }
+        encoded.truncate(encoded.len() - count);
+
+        let codes = self.codes(&keys.s);
+        let rows = [
+            codes[8].1.as_bytes()[0] - b'0',
+            codes[18].1.as_bytes()[0] - b'0',
+        ];
+        let mut plaintext = String::new();
+        let mut i = 0;
+        while i < encoded.len() {
+            let code = if rows.contains(&encoded[i]) {
+                if i + 1 == encoded.len() {
+                    break;
+                }
+                i += 2;
+                digits(&encoded[i - 2..i])
+            } else {
+                i += 1;
+                digits(&encoded[i - 1..i])
+            };
+            let &(symbol, _) = codes.iter().find(|&(_, c)| *c == code).unwrap();
+            plaintext.push(symbol);
+        }
+
+        Ok(plaintext)
+    }
+
+    // Numbers the cells of the checkerboard with the column digits of line S, in
+    // the order the characters are written on the board.
+    fn codes(&self, s: &str) -> Vec<(char, String)> {
+        let header = s.chars().collect::<Vec<char>>();
+        let blanks = (0..10)
+            .filter(|&i| self.board[i] == ' ')
+            .map(|i| header[i])
+            .collect::<Vec<char>>();
+
+        self.board
+            .iter()
+            .enumerate()
+            .filter(|&(_, &c)| c != ' ')
+            .map(|(i, &c)| {
+                let code = match i / 10 {
+                    0 => header[i].to_string(),
+                    row => format!("{}{}", blanks[row - 1], header[i % 10]),
+                };
+                (c, code)
+            })
+            .collect()
+    }
+
+    // How many groups from the end the message indicator is placed.
+    fn indicator_place(&self) -> usize {
+        match self.date[5] {
+            0 => 10,
+            d => d as usize,
+        }
+    }
+}
+
+// Numbers the characters in alphabetical order, with ties left to right. The
+// tenth is numbered 0, as the VIC lines are written.
+fn sequence<T: Ord>(items: &[T]) -> Vec<u8> {
+    let mut order = (0..items.len()).collect::<Vec<usize>>();
+    order.sort_by_key(|&i| &items[i]);
+    let mut ranks = vec![0; items.len()];
+    for (rank, &i) in order.iter().enumerate() {
+        ranks[i] = ((rank + 1) % 10) as u8;
+    }
+    ranks
+}
+
+// Extends the digits by chain addition, adding each pair of neighbouring digits
+// without carrying, until there are as many as the length.
+fn chain(start: &[u8], length: usize) -> Vec<u8> {
+    let mut digits = start.to_vec();
+    let mut i = 0;
+    while digits.len() < length {
+        let d = (digits[i] + digits[i + 1]) % 10;
+        digits.push(d);
+        i += 1;
+    }
+    digits.truncate(length);
+    digits
+}
+
+// Counts 0 as the highest digit, as it stands for 10 when digits are numbered.
+fn tens(d: &[u8]) -> Vec<u8> {
+    d.iter().map(|&d| (d + 9) % 10).collect()
+}
+
+fn digits(d: &[u8]) -> String {
+    d.iter().map(|&d| (d + b'0') as char).collect()
+}
+
+// Writes a digit key as letters so that 0 is taken after 9, as it is in the VIC
+// transpositions.
+fn key_letters(key: &str) -> String {
+    key.bytes()
+        .map(|b| (b'A' + (b - b'0' + 9) % 10) as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chain, sequence, Vic, CHECKERBOARD};
+
+    // A hand-worked example, not a published message.
+    const PHRASE: &str = "I dream of Jeannie with the light brown hair";
+
+    fn vic() -> Vic {
+        Vic::new(PHRASE, "130959", 8, CHECKERBOARD).unwrap()
+    }
+
+    #[test]
+    fn short_phrase() {
+        assert!(Vic::new("I dream of Jeannie", "130959", 8, CHECKERBOARD).is_err());
+    }
+
+    #[test]
+    fn invalid_date() {
+        assert!(Vic::new(PHRASE, "13959", 8, CHECKERBOARD).is_err());
+    }
+
+    #[test]
+    fn invalid_personal_number() {
+        assert!(Vic::new(PHRASE, "130959", 16, CHECKERBOARD).is_err());
+    }
+
+    #[test]
+    fn invalid_checkerboard() {
+        let board = "AT ONE SIRACDFGHJKLMPQUVWXYZ/.";
+        assert!(Vic::new(PHRASE, "130959", 8, board).is_err());
+    }
+
+    #[test]
+    fn invalid_indicator() {
+        assert!(vic().keys("7240").is_err());
+    }
+
+    #[test]
+    fn sequencing() {
+        let letters = "IDREAMOFJE".chars().collect::<Vec<char>>();
+        assert_eq!(vec![6, 2, 0, 3, 1, 8, 9, 5, 7, 4], sequence(&letters));
+    }
+
+    #[test]
+    fn chain_addition() {
+        assert_eq!(
+            vec![6, 9, 5, 1, 2, 5, 4, 6, 3, 7],
+            chain(&[6, 9, 5, 1, 2], 10)
+        );
+    }
+
+    #[test]
+    fn key_derivation() {
+        let keys = vic().keys("72401").unwrap();
+        assert_eq!("13095", keys.b);
+        assert_eq!("69416", keys.c);
+        assert_eq!("IDREAMOFJEANNIEWITHT", keys.d);
+        assert_eq!("62031895741674205839", keys.e);
+        assert_eq!("69416535711234567890", keys.f);
+        assert_eq!("2144732045", keys.g);
+        assert_eq!("6144576942", keys.h);
+        assert_eq!("7134698052", keys.j);
+    }
+
+    #[test]
+    fn transposition_keys() {
+        let keys = vic().keys("72401").unwrap();
+        assert_eq!("3238241234", keys.block[4]);
+        assert_eq!((11, 12), keys.lengths);
+        assert_eq!("53082916148", keys.q);
+        assert_eq!("784391698656", keys.r);
+        assert_eq!("5260381479", keys.s);
+    }
+
+    #[test]
+    fn checkerboard() {
+        let stages = vic().stages("We are discovered", "72401").unwrap();
+        assert_eq!("1385986674620108986619194", stages.encoded);
+    }
+
+    #[test]
+    fn encipher() {
+        let stages = vic()
+            .stages("We are discovered. Flee at once", "72401")
+            .unwrap();
+        assert_eq!("6854760988130084131262662507969689898161", stages.first);
+        assert_eq!("6114882590679666308198166318002568784299", stages.second);
+        assert_eq!(
+            "72401 61148 82590 67966 63081 98166 31800 25687 84299",
+            stages.ciphertext
+        );
+    }
+
+    #[test]
+    fn decipher() {
+        assert_eq!(
+            "WEAREDISCOVERED.FLEEATONCE",
+            vic()
+                .decipher("72401 61148 82590 67966 63081 98166 31800 25687 84299")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_trailing_pad_character() {
+        let ciphertext = vic().encipher("Attack at dawn.", "72401").unwrap();
+        assert_eq!("ATTACKATDAWN.", vic().decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn indicator_place() {
+        let v = Vic::new(PHRASE, "130952", 8, CHECKERBOARD).unwrap();
+        let ciphertext = v
+            .encipher("We are discovered. Flee at once", "72401")
+            .unwrap();
+        let groups = ciphertext.split(' ').collect::<Vec<&str>>();
+        assert_eq!("72401", groups[groups.len() - 2]);
+        assert_eq!(
+            "WEAREDISCOVERED.FLEEATONCE",
+            v.decipher(&ciphertext).unwrap()
+        );
+    }
+}
@@ -1,3 +1,6 @@
+use ciphers::progressive::ShiftCipher;
+use common::ALPHABET;
+
 /// Vigenere Cipher
 ///
 /// The struct is generated through the new() function.
@@ -74,7 +77,7 @@ impl Vigenere {
 
     // Uses the converted key to perform the encipher or decipher of a message.
     //
-    fn transpose(filter: &[u8], text: &str) -> Result<String, &'static str> {
+    pub(crate) fn transpose(filter: &[u8], text: &str) -> Result<String, &'static str> {
         let mut filter_index = 0;
         let mut result = String::new();
 
@@ -112,6 +115,16 @@ impl Vigenere {
     }
 }
 
+impl ShiftCipher for Vigenere {
+    fn shifts(&self) -> Result<Vec<u8>, String> {
+        self.convert_key()
+    }
+
+    fn alphabet(&self) -> Vec<char> {
+        ALPHABET.chars().collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Vigenere;
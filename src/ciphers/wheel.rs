@@ -0,0 +1,286 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The row of the wheel the ciphertext is read from, counted down from the row
+/// the plaintext is set on.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Offset {
+    /// Every line is read from the same row, from 1 to 25.
+    Fixed(usize),
+    /// Each line is read from a random row, generated from a seed so that the
+    /// rows can be recreated to decipher.
+    Random(u64),
+}
+
+/// Jefferson Wheel Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct JeffersonWheel {
+    disks: Vec<Vec<char>>,
+    offset: Offset,
+}
+
+impl JeffersonWheel {
+    /// Initializes a Jefferson wheel cipher with a set of disk alphabets, the
+    /// order the disks are stacked on the axle as the key, and the offset of the
+    /// row the ciphertext is read from.
+    ///
+    /// The order lists the indices of the disks in the set, so a set of disks can
+    /// be shared while the order is changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::wheel::{JeffersonWheel, Offset};
+    ///
+    /// let disks = [
+    ///     "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    ///     "ZYXWVUTSRQPONMLKJIHGFEDCBA",
+    ///     "KRYPTOSABCDEFGHIJLMNQUVWXZ",
+    /// ];
+    /// let w = JeffersonWheel::new(&disks, &[2, 0, 1], Offset::Fixed(1)).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if a disk is not 26 unique letters, the order is empty,
+    /// names a disk that is not in the set or uses a disk twice, or a fixed offset
+    /// is not between 1 and 25.
+    ///
+    pub fn new(disks: &[&str], order: &[usize], offset: Offset) -> Result<Self, String> {
+        let mut alphabets = Vec::new();
+        for disk in disks {
+            let disk = disk.to_ascii_uppercase();
+            if disk.len() != 26 || !disk.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(String::from("Each disk must be 26 letters"));
+            }
+            if disk.chars().enumerate().any(|(i, c)| disk[..i].contains(c)) {
+                return Err(String::from("Disk letters must be unique"));
+            }
+            alphabets.push(disk.chars().collect::<Vec<char>>());
+        }
+
+        if order.is_empty() {
+            return Err(String::from("At least one disk must be used"));
+        }
+        if order.iter().any(|&d| d >= disks.len()) {
+            return Err(String::from(
+                "Disk order names a disk that is not in the set",
+            ));
+        }
+        if order
+            .iter()
+            .enumerate()
+            .any(|(i, d)| order[..i].contains(d))
+        {
+            return Err(String::from("Each disk can only be used once"));
+        }
+        if let Offset::Fixed(n) = offset {
+            if !(1..=25).contains(&n) {
+                return Err(String::from("Offset must be between 1 and 25"));
+            }
+        }
+
+        Ok(JeffersonWheel {
+            disks: order.iter().map(|&d| alphabets[d].clone()).collect(),
+            offset,
+        })
+    }
+
+    /// Enciphers a message with a Jefferson wheel cipher.
+    ///
+    /// Characters other than letters are removed and the message is set on the
+    /// wheel a line at a time, with as many letters to a line as there are disks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::wheel::{JeffersonWheel, Offset};
+    ///
+    /// let disks = [
+    ///     "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    ///     "ZYXWVUTSRQPONMLKJIHGFEDCBA",
+    ///     "KRYPTOSABCDEFGHIJLMNQUVWXZ",
+    /// ];
+    /// let w = JeffersonWheel::new(&disks, &[2, 0, 1], Offset::Fixed(1)).unwrap();
+    /// assert_eq!("IFKMP", w.encipher("Hello").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &str) -> Result<String, String> {
+        Ok(self.turn(plaintext, false))
+    }
+
+    /// Deciphers a message with a Jefferson wheel cipher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::wheel::{JeffersonWheel, Offset};
+    ///
+    /// let disks = [
+    ///     "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    ///     "ZYXWVUTSRQPONMLKJIHGFEDCBA",
+    ///     "KRYPTOSABCDEFGHIJLMNQUVWXZ",
+    /// ];
+    /// let w = JeffersonWheel::new(&disks, &[2, 0, 1], Offset::Fixed(1)).unwrap();
+    /// assert_eq!("HELLO", w.decipher("IFKMP").unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &str) -> Result<String, String> {
+        Ok(self.turn(ciphertext, true))
+    }
+
+    /// Lists every generatrix of each line of a message, as seen by turning the
+    /// wheel to set the line on its first row.
+    ///
+    /// The first generatrix of a line is the line itself, and the plaintext can
+    /// be found by looking for the row that reads as language, without knowing
+    /// the offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::wheel::{JeffersonWheel, Offset};
+    ///
+    /// let disks = [
+    ///     "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    ///     "ZYXWVUTSRQPONMLKJIHGFEDCBA",
+    ///     "KRYPTOSABCDEFGHIJLMNQUVWXZ",
+    /// ];
+    /// let w = JeffersonWheel::new(&disks, &[2, 0, 1], Offset::Random(7)).unwrap();
+    /// let lines = w.generatrices(&w.encipher("Hello").unwrap());
+    /// assert!(lines[0].contains(&String::from("HEL")));
+    /// assert!(lines[1].contains(&String::from("LO")));
+    /// ```
+    ///
+    pub fn generatrices(&self, ciphertext: &str) -> Vec<Vec<String>> {
+        JeffersonWheel::letters(ciphertext)
+            .chunks(self.disks.len())
+            .map(|line| {
+                (0..26)
+                    .map(|row| {
+                        line.iter()
+                            .zip(self.disks.iter())
+                            .map(|(&c, disk)| disk[(JeffersonWheel::find(disk, c) + row) % 26])
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Sets each line of the text on the wheel and reads it from the row at the
+    // line's offset, counting back up the wheel to reverse the cipher.
+    fn turn(&self, text: &str, reverse: bool) -> String {
+        let seed = match self.offset {
+            Offset::Random(seed) => seed,
+            Offset::Fixed(_) => 0,
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut result = String::new();
+        for line in JeffersonWheel::letters(text).chunks(self.disks.len()) {
+            let offset = match self.offset {
+                Offset::Fixed(n) => n,
+                Offset::Random(_) => rng.gen_range(1..26),
+            };
+            let row = if reverse { 26 - offset } else { offset };
+            for (&c, disk) in line.iter().zip(self.disks.iter()) {
+                result.push(disk[(JeffersonWheel::find(disk, c) + row) % 26]);
+            }
+        }
+        result
+    }
+
+    // The letters of a text in upper case.
+    fn letters(text: &str) -> Vec<char> {
+        text.chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    // The position of a letter on a disk.
+    fn find(disk: &[char], c: char) -> usize {
+        disk.iter().position(|&d| d == c).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JeffersonWheel, Offset};
+
+    const DISKS: [&str; 3] = [
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+        "ZYXWVUTSRQPONMLKJIHGFEDCBA",
+        "KRYPTOSABCDEFGHIJLMNQUVWXZ",
+    ];
+
+    #[test]
+    fn invalid_disk() {
+        assert!(JeffersonWheel::new(&["ABC"], &[0], Offset::Fixed(1)).is_err());
+    }
+
+    #[test]
+    fn repeated_disk_letter() {
+        let disks = ["ABCDEFGHIJKLMNOPQRSTUVWXYA"];
+        assert!(JeffersonWheel::new(&disks, &[0], Offset::Fixed(1)).is_err());
+    }
+
+    #[test]
+    fn missing_disk() {
+        assert!(JeffersonWheel::new(&DISKS, &[0, 3], Offset::Fixed(1)).is_err());
+    }
+
+    #[test]
+    fn repeated_disk() {
+        assert!(JeffersonWheel::new(&DISKS, &[0, 0], Offset::Fixed(1)).is_err());
+    }
+
+    #[test]
+    fn invalid_offset() {
+        assert!(JeffersonWheel::new(&DISKS, &[0, 1], Offset::Fixed(26)).is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let w = JeffersonWheel::new(&DISKS, &[2, 0, 1], Offset::Fixed(1)).unwrap();
+        assert_eq!("IFKMP", w.encipher("Hel, lo").unwrap());
+    }
+
+    #[test]
+    fn decipher() {
+        let w = JeffersonWheel::new(&DISKS, &[2, 0, 1], Offset::Fixed(1)).unwrap();
+        assert_eq!("HELLO", w.decipher("IFKMP").unwrap());
+    }
+
+    #[test]
+    fn random_offset() {
+        let w = JeffersonWheel::new(&DISKS, &[1, 2, 0], Offset::Random(1922)).unwrap();
+        let ciphertext = w.encipher("Attack at dawn").unwrap();
+        assert_eq!("ATTACKATDAWN", w.decipher(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn random_offset_is_seeded() {
+        let a = JeffersonWheel::new(&DISKS, &[1, 2, 0], Offset::Random(5)).unwrap();
+        let b = JeffersonWheel::new(&DISKS, &[1, 2, 0], Offset::Random(5)).unwrap();
+        assert_eq!(
+            a.encipher("Attack at dawn").unwrap(),
+            b.encipher("Attack at dawn").unwrap()
+        );
+    }
+
+    #[test]
+    fn generatrices() {
+        let w = JeffersonWheel::new(&DISKS, &[2, 0, 1], Offset::Fixed(1)).unwrap();
+        let lines = w.generatrices("IFKMP");
+        assert_eq!(2, lines.len());
+        assert_eq!(26, lines[0].len());
+        assert_eq!("IFK", lines[0][0]);
+        assert_eq!("HEL", lines[0][25]);
+        assert_eq!("LO", lines[1][25]);
+    }
+}
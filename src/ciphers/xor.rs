@@ -0,0 +1,104 @@
+/// Repeating-Key XOR Cipher
+///
+/// The struct is generated through the new() function.
+///
+pub struct RepeatingXor {
+    key: Vec<u8>,
+}
+
+impl RepeatingXor {
+    /// Initializes a repeating-key xor cipher with a supplied key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::xor::RepeatingXor;
+    ///
+    /// let x = RepeatingXor::new(b"ICE").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return Err() if the key is empty.
+    ///
+    pub fn new(key: &[u8]) -> Result<Self, String> {
+        if key.is_empty() {
+            return Err(String::from("Key must not be empty"));
+        }
+        Ok(RepeatingXor { key: key.to_vec() })
+    }
+
+    /// Enciphers a message by combining each byte with the next byte of the key
+    /// by exclusive or, starting the key again when it runs out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::xor::RepeatingXor;
+    ///
+    /// let x = RepeatingXor::new(b"ICE").unwrap();
+    /// assert_eq!(vec![0x0b, 0x36, 0x37, 0x27], x.encipher(b"Burn").unwrap());
+    /// ```
+    ///
+    pub fn encipher(&self, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+        Ok(self.crypt(plaintext))
+    }
+
+    /// Deciphers a message with a repeating-key xor cipher, which is its own
+    /// inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kryptos::ciphers::xor::RepeatingXor;
+    ///
+    /// let x = RepeatingXor::new(b"ICE").unwrap();
+    /// assert_eq!(b"Burn".to_vec(), x.decipher(&[0x0b, 0x36, 0x37, 0x27]).unwrap());
+    /// ```
+    ///
+    pub fn decipher(&self, ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+        Ok(self.crypt(ciphertext))
+    }
+
+    fn crypt(&self, text: &[u8]) -> Vec<u8> {
+        text.iter()
+            .zip(self.key.iter().cycle())
+            .map(|(b, k)| b ^ k)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RepeatingXor;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn empty_key() {
+        assert!(RepeatingXor::new(b"").is_err());
+    }
+
+    #[test]
+    fn encipher() {
+        let x = RepeatingXor::new(b"ICE").unwrap();
+        let plaintext = "Burning 'em, if you ain't quick and nimble\n\
+                         I go crazy when I hear a cymbal";
+        assert_eq!(
+            "0b3637272a2b2e63622c2e69692a23693a2a3c6324202d623d63343c2a26226324272765272a\
+             282b2f20430a652e2c652a3124333a653e2b2027630c692b20283165286326302e27282f",
+            hex(&x.encipher(plaintext.as_bytes()).unwrap())
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let x = RepeatingXor::new(&[0x00, 0xff, 0x80]).unwrap();
+        let plaintext = (0..=255).collect::<Vec<u8>>();
+        let ciphertext = x.encipher(&plaintext).unwrap();
+        assert_ne!(plaintext, ciphertext);
+        assert_eq!(plaintext, x.decipher(&ciphertext).unwrap());
+    }
+}
@@ -0,0 +1,144 @@
+// A transposition grid that text is written into and read out of by cells.
+//
+// The grid has a fixed width and as many rows as the text needs, so the last row
+// may be short. Each cell holds the positions in the text of the characters
+// written into it, which lets a transposition be expressed as the order the
+// positions are read out in.
+pub struct Grid {
+    width: usize,
+    sizes: Vec<usize>,
+    cells: Vec<Vec<usize>>,
+}
+
+impl Grid {
+    // Creates a grid of single character cells written by rows.
+    pub fn new(width: usize, length: usize) -> Grid {
+        Grid::with_sizes(width, length, |_, _| 1)
+    }
+
+    // Creates a grid with cells of the given sizes written by rows. The last cell
+    // is shortened to fit the text.
+    pub fn with_sizes<F>(width: usize, length: usize, size: F) -> Grid
+    where
+        F: Fn(usize, usize) -> usize,
+    {
+        let mut sizes = Vec::new();
+        let mut remaining = length;
+        while remaining > 0 {
+            let p = sizes.len();
+            let s = size(p / width, p % width).min(remaining);
+            sizes.push(s);
+            remaining -= s;
+        }
+        sizes.resize(sizes.len().div_ceil(width) * width, 0);
+
+        let mut grid = Grid {
+            width,
+            sizes,
+            cells: Vec::new(),
+        };
+        let positions = (0..grid.sizes.len())
+            .map(|p| (p / width, p % width))
+            .collect::<Vec<(usize, usize)>>();
+        grid.write(&positions);
+        grid
+    }
+
+    pub fn rows(&self) -> usize {
+        self.sizes.len() / self.width
+    }
+
+    // Whether the grid has a cell at the row and column.
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        col < self.width && row < self.rows() && self.sizes[row * self.width + col] > 0
+    }
+
+    // Rewrites the text into the cells in the order of the given positions.
+    pub fn write(&mut self, positions: &[(usize, usize)]) {
+        let mut next = 0;
+        self.cells = vec![Vec::new(); self.sizes.len()];
+        for &(row, col) in positions {
+            let p = row * self.width + col;
+            self.cells[p] = (next..next + self.sizes[p]).collect();
+            next += self.sizes[p];
+        }
+    }
+
+    // The text positions held by the cells at the given positions, in order.
+    pub fn read(&self, positions: &[(usize, usize)]) -> Vec<usize> {
+        positions
+            .iter()
+            .filter(|&&(row, col)| self.contains(row, col))
+            .flat_map(|&(row, col)| self.cells[row * self.width + col].clone())
+            .collect()
+    }
+
+    // The text positions read down groups of columns in turn. Columns in the same
+    // group are read together a row at a time, from left to right.
+    pub fn read_columns(&self, groups: &[Vec<usize>]) -> Vec<usize> {
+        let mut positions = Vec::new();
+        for group in groups {
+            for row in 0..self.rows() {
+                for &col in group {
+                    positions.push((row, col));
+                }
+            }
+        }
+        self.read(&positions)
+    }
+
+    // Rearranges the text so that the character at each position of the order
+    // comes next.
+    pub fn transpose(text: &[char], order: &[usize]) -> String {
+        order.iter().map(|&p| text[p]).collect()
+    }
+
+    // Reverses transpose() by returning each character to its position.
+    pub fn restore(text: &[char], order: &[usize]) -> String {
+        let mut result = vec![' '; text.len()];
+        for (i, &p) in order.iter().enumerate() {
+            result[p] = text[i];
+        }
+        result.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    #[test]
+    fn irregular_last_row() {
+        let g = Grid::new(4, 10);
+        assert_eq!(3, g.rows());
+        assert!(g.contains(2, 1));
+        assert!(!g.contains(2, 2));
+    }
+
+    #[test]
+    fn read_columns() {
+        let g = Grid::new(3, 7);
+        assert_eq!(
+            vec![2, 5, 0, 3, 6, 1, 4],
+            g.read_columns(&[vec![2], vec![0], vec![1]])
+        );
+    }
+
+    #[test]
+    fn sized_cells() {
+        let g = Grid::with_sizes(2, 5, |row, col| 1 + (row + col) % 2);
+        assert_eq!(vec![0, 3, 4, 1, 2], g.read_columns(&[vec![0], vec![1]]));
+    }
+
+    #[test]
+    fn restore_transposition() {
+        let text = "abcdefg".chars().collect::<Vec<char>>();
+        let order = vec![2, 5, 0, 3, 6, 1, 4];
+        let ciphertext = Grid::transpose(&text, &order);
+        assert_eq!("cfadgbe", ciphertext);
+        assert_eq!(
+            "abcdefg",
+            Grid::restore(&ciphertext.chars().collect::<Vec<char>>(), &order)
+        );
+    }
+}
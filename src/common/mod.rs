@@ -1,4 +1,7 @@
+pub mod grid;
+
 pub const ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+pub const SQUARE_ALPHABET: &str = "ABCDEFGHIKLMNOPQRSTUVWXYZ";
 
 pub fn binary_to_char(bin: &str) -> Result<char, String> {
     let binary = bin.as_bytes().iter().map(|b| b - 48).collect::<Vec<u8>>();
@@ -8,12 +11,41 @@ pub fn binary_to_char(bin: &str) -> Result<char, String> {
         }
     }
 
-    Ok((binary.iter().fold(0, |x, &b| x * 2 + b as u8) + 65) as char)
+    Ok((binary.iter().fold(0, |x, &b| x * 2 + b) + 65) as char)
+}
+
+// Builds a keyword-mixed alphabet by writing the unique characters of the key
+// that appear in the alphabet first, followed by the rest of the alphabet.
+pub fn keyed_alphabet(key: &str, alphabet: &str) -> String {
+    let mut keyed = String::new();
+    for c in key.chars().chain(alphabet.chars()) {
+        let c = c.to_ascii_uppercase();
+        if alphabet.contains(c) && !keyed.contains(c) {
+            keyed.push(c);
+        }
+    }
+    keyed
+}
+
+// Builds a keyword-mixed 5x5 square, merging J into I.
+pub fn keyed_square(key: &str) -> Vec<char> {
+    keyed_alphabet(&key.to_ascii_uppercase().replace('J', "I"), SQUARE_ALPHABET)
+        .chars()
+        .collect()
+}
+
+// Calculates the order columns are read in for a transposition key. Columns
+// are taken alphabetically by key letter, with ties broken from left to right.
+pub fn column_order(key: &str) -> Vec<usize> {
+    let mut order = (0..key.chars().count()).collect::<Vec<usize>>();
+    let key = key.to_ascii_uppercase().chars().collect::<Vec<char>>();
+    order.sort_by_key(|&i| key[i]);
+    order
 }
 
 #[cfg(test)]
 mod tests {
-    use super::binary_to_char;
+    use super::{binary_to_char, column_order, keyed_alphabet, keyed_square, ALPHABET};
 
     #[test]
     fn valid_binary() {
@@ -29,4 +61,30 @@ mod tests {
     fn number_to_char() {
         assert_eq!('C', binary_to_char("010").unwrap());
     }
+
+    #[test]
+    fn keyword_mixed_alphabet() {
+        assert_eq!(
+            "KRYPTOSABCDEFGHIJLMNQUVWXZ",
+            keyed_alphabet("kryptos", ALPHABET)
+        );
+    }
+
+    #[test]
+    fn keyword_mixed_square() {
+        assert_eq!(
+            "MAIXBCDEFGHKLNOPQRSTUVWYZ",
+            keyed_square("maJix").iter().collect::<String>()
+        );
+    }
+
+    #[test]
+    fn transposition_column_order() {
+        assert_eq!(vec![4, 2, 1, 3, 5, 0], column_order("zebras"));
+    }
+
+    #[test]
+    fn repeated_letter_column_order() {
+        assert_eq!(vec![3, 2, 1, 5, 0, 4], column_order("tomato"));
+    }
 }
@@ -0,0 +1,266 @@
+//! Keyword-mixed alphabets for substitution ciphers.
+//!
+//! Each function returns a 26 letter ciphertext alphabet, written under the
+//! straight plaintext alphabet, that can be passed to `Substitution::new`.
+//!
+//! # Examples
+//!
+//! ```
+//! use kryptos::ciphers::substitution::Substitution;
+//! use kryptos::keygen;
+//!
+//! let alphabet = keygen::k2("kryptos", 0).unwrap();
+//! let s = Substitution::new(&alphabet).unwrap();
+//! assert_eq!("Ateeh", s.encipher("Hello").unwrap());
+//! ```
+//!
+use common::{column_order, keyed_alphabet, ALPHABET};
+
+/// Builds an alphabet from the unique letters of a keyword followed by the rest
+/// of the alphabet in order.
+///
+/// # Examples
+///
+/// ```
+/// use kryptos::keygen;
+///
+/// assert_eq!(
+///     "KRYPTOSABCDEFGHIJLMNQUVWXZ",
+///     keygen::keyword_mixed("kryptos").unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Will return Err() if the keyword is not alphabetic.
+///
+pub fn keyword_mixed(keyword: &str) -> Result<String, String> {
+    validate(keyword)?;
+    Ok(keyed_alphabet(keyword, ALPHABET))
+}
+
+/// Builds an alphabet from the unique letters of a keyword followed by the rest
+/// of the alphabet in reverse order.
+///
+/// # Examples
+///
+/// ```
+/// use kryptos::keygen;
+///
+/// assert_eq!(
+///     "KRYPTOSZXWVUQNMLJIHGFEDCBA",
+///     keygen::reversed("kryptos").unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Will return Err() if the keyword is not alphabetic.
+///
+pub fn reversed(keyword: &str) -> Result<String, String> {
+    validate(keyword)?;
+    let reverse = ALPHABET.chars().rev().collect::<String>();
+    Ok(keyed_alphabet(keyword, &reverse))
+}
+
+/// Builds an alphabet by writing the keyword mixed alphabet in rows under the
+/// unique letters of the keyword, then reading the columns in the alphabetical
+/// order of the keyword.
+///
+/// # Examples
+///
+/// ```
+/// use kryptos::keygen;
+///
+/// assert_eq!(
+///     "AHMSXBFKPVEDJOURGLQWZCINTY",
+///     keygen::columnar_mixed("zebra").unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Will return Err() if the keyword is empty or not alphabetic.
+///
+pub fn columnar_mixed(keyword: &str) -> Result<String, String> {
+    if keyword.is_empty() {
+        return Err(String::from("Keyword must not be empty"));
+    }
+    let mixed = keyword_mixed(keyword)?.chars().collect::<Vec<char>>();
+    let header = mixed
+        .iter()
+        .take_while(|&&c| keyword.to_ascii_uppercase().contains(c));
+    let width = header.count();
+
+    let mut alphabet = String::new();
+    for col in column_order(&mixed[..width].iter().collect::<String>()) {
+        alphabet.extend(mixed.iter().skip(col).step_by(width));
+    }
+    Ok(alphabet)
+}
+
+/// Builds an ACA K1 alphabet, where the plaintext alphabet is keyword mixed and
+/// the ciphertext alphabet is straight, slid by the shift.
+///
+/// # Examples
+///
+/// ```
+/// use kryptos::keygen;
+///
+/// assert_eq!(
+///     "HIJKLMNOPQARSTFDUBGEVWXYCZ",
+///     keygen::k1("kryptos", 0).unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Will return Err() if the keyword is not alphabetic.
+///
+pub fn k1(keyword: &str, shift: u8) -> Result<String, String> {
+    Ok(align(&keyword_mixed(keyword)?, ALPHABET, shift))
+}
+
+/// Builds an ACA K2 alphabet, where the plaintext alphabet is straight and the
+/// ciphertext alphabet is keyword mixed, slid by the shift.
+///
+/// # Examples
+///
+/// ```
+/// use kryptos::keygen;
+///
+/// assert_eq!(
+///     "PTOSABCDEFGHIJLMNQUVWXZKRY",
+///     keygen::k2("kryptos", 3).unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Will return Err() if the keyword is not alphabetic.
+///
+pub fn k2(keyword: &str, shift: u8) -> Result<String, String> {
+    Ok(align(ALPHABET, &keyword_mixed(keyword)?, shift))
+}
+
+/// Builds an ACA K3 alphabet, where the plaintext and ciphertext alphabets are
+/// the same keyword mixed alphabet, with the ciphertext alphabet slid by the
+/// shift.
+///
+/// # Examples
+///
+/// ```
+/// use kryptos::keygen;
+///
+/// assert_eq!(
+///     "BCDEFGHIJLRMNQSTUYAOVWXZPK",
+///     keygen::k3("kryptos", 1).unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Will return Err() if the keyword is not alphabetic or the shift is a multiple
+/// of 26, which would leave every letter unchanged.
+///
+pub fn k3(keyword: &str, shift: u8) -> Result<String, String> {
+    if shift.is_multiple_of(26) {
+        return Err(String::from("Shift must not be a multiple of 26"));
+    }
+    let mixed = keyword_mixed(keyword)?;
+    Ok(align(&mixed, &mixed, shift))
+}
+
+// Writes the ciphertext alphabet, slid left by the shift, under the plaintext
+// alphabet and reads off the letter under each letter of the straight alphabet.
+fn align(plain: &str, cipher: &str, shift: u8) -> String {
+    let cipher = cipher.chars().collect::<Vec<char>>();
+    ALPHABET
+        .chars()
+        .map(|c| {
+            let index = plain.chars().position(|p| p == c).unwrap();
+            cipher[(index + shift as usize) % 26]
+        })
+        .collect()
+}
+
+// Checks a keyword is alphabetic.
+fn validate(keyword: &str) -> Result<(), String> {
+    if keyword.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(())
+    } else {
+        Err(String::from("Keyword must be alphabetic"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{columnar_mixed, k1, k2, k3, keyword_mixed, reversed};
+    use ciphers::substitution::Substitution;
+
+    #[test]
+    fn invalid_keyword() {
+        assert!(keyword_mixed("kryptos1").is_err());
+    }
+
+    #[test]
+    fn keyword_then_rest() {
+        assert_eq!(
+            "KRYPTOSABCDEFGHIJLMNQUVWXZ",
+            keyword_mixed("kryptos").unwrap()
+        );
+    }
+
+    #[test]
+    fn reversed_rest() {
+        assert_eq!("KRYPTOSZXWVUQNMLJIHGFEDCBA", reversed("kryptos").unwrap());
+    }
+
+    #[test]
+    fn columnar() {
+        assert_eq!(
+            "AHMSXBFKPVEDJOURGLQWZCINTY",
+            columnar_mixed("zebra").unwrap()
+        );
+    }
+
+    #[test]
+    fn columnar_repeated_letters() {
+        assert_eq!(
+            "ADIQVBCHPUZLEJRWNGMTYOFKSX",
+            columnar_mixed("balloon").unwrap()
+        );
+    }
+
+    #[test]
+    fn columnar_empty_keyword() {
+        assert!(columnar_mixed("").is_err());
+    }
+
+    #[test]
+    fn k1_alphabet() {
+        assert_eq!("HIJKLMNOPQARSTFDUBGEVWXYCZ", k1("kryptos", 0).unwrap());
+    }
+
+    #[test]
+    fn k2_alphabet() {
+        assert_eq!("PTOSABCDEFGHIJLMNQUVWXZKRY", k2("kryptos", 3).unwrap());
+    }
+
+    #[test]
+    fn k3_alphabet() {
+        assert_eq!("BCDEFGHIJLRMNQSTUYAOVWXZPK", k3("kryptos", 1).unwrap());
+    }
+
+    #[test]
+    fn k3_without_shift() {
+        assert!(k3("kryptos", 26).is_err());
+    }
+
+    #[test]
+    fn k1_enciphers_with_keyword_plain_alphabet() {
+        // In K1 the keyword mixed plaintext letter K sits over ciphertext A.
+        let s = Substitution::new(&k1("kryptos", 0).unwrap()).unwrap();
+        assert_eq!("ABG", s.encipher("KRS").unwrap());
+    }
+}
@@ -1,2 +1,5 @@
+extern crate rand;
+
 pub mod ciphers;
 mod common;
+pub mod keygen;
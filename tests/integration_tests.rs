@@ -7,7 +7,7 @@ fn test_regex() {
     let binary = "01010";
 
     let re = Regex::new(r"[01]{5}").unwrap();
-    let result = re.replace_all(&binary, |caps: &Captures| {
+    let result = re.replace_all(binary, |caps: &Captures| {
         format!(
             "{:?}",
             &caps[0]